@@ -9,7 +9,7 @@ from rlbot.utils.structures.game_data_struct import (BoostPad, BoxShape,
                                                      Vector3)
 
 from rlutilities.linear_algebra import vec3
-from rlutilities.simulation import Ball, Field, Game, Input
+from rlutilities.simulation import Ball, Field, Game, Input, sphere
 from rlutilities.mechanics import Drive
 
 
@@ -81,6 +81,9 @@ def get_random_packet():
 
     return packet
 
+def close(a: vec3, b: vec3, eps: float = 0.01) -> bool:
+    return all(abs(a[i] - b[i]) < eps for i in range(3))
+
 start_time = time_ns()
 Game.set_mode("soccar")
 
@@ -122,6 +125,11 @@ print(f"6 second ball prediction generation: {(time_ns() - start_time) / 1e6}ms"
 assert game.ball.position != new_ball.position
 assert new_ball.time > 5.9 + game.ball.time and new_ball.time < 6.1 + game.ball.time
 
+prediction = Ball(game.ball).predict(6)
+assert len(prediction) == 6 * 120 + 1
+assert prediction[-1].position == new_ball.position
+assert prediction[-1].time == new_ball.time
+
 assert len(game.cars) == 8
 assert game.cars[2].position != vec3(0, 0, 0)
 
@@ -134,3 +142,57 @@ action.step(1/120)
 assert action.controls != Input()
 
 print(f"Get drive controls: {(time_ns() - start_time) / 1e6}ms")
+
+car = game.cars[2]
+old_time, old_position = car.time, car.position
+car.step(Input(), 1/120)
+assert car.time > old_time
+assert car.position != old_position
+
+trajectory = game.cars[2].simulate(Input(), steps=30)
+assert len(trajectory) == 30
+assert trajectory.positions.shape == (30, 3)
+
+try:
+    Game(mode="bogus")
+except ValueError:
+    pass
+else:
+    assert False, "Game accepted an unknown mode"
+
+field = Field()
+assert field.collide(sphere(vec3(0, 0, 50), 100)) is not None
+assert field.collide(sphere(vec3(0, 0, 500), 100)) is None
+
+point, normal, distance = field.raycast(vec3(0, 0, 500), vec3(0, 0, -1), 1000)
+assert abs(distance - 500) < 1
+assert close(point, vec3(0, 0, 0), 1)
+assert close(normal, vec3(0, 0, 1))
+
+try:
+    field.raycast(vec3(0, 0, 500), vec3(0, 0, 0), 1000)
+except ValueError:
+    pass
+else:
+    assert False, "raycast accepted a zero direction"
+
+surface, normal, distance = field.nearest_surface(vec3(0, 0, 500))
+assert abs(distance - 500) < 1
+assert close(normal, vec3(0, 0, 1))
+
+below = field.nearest_surface(vec3(0, 0, -50))
+assert below is not None and below[2] < 0
+
+car = game.cars[2]
+point = vec3(100, -200, 300)
+assert close(car.to_world(car.to_local(point)), point)
+
+for i in range(3):
+    assert car.forward()[i] == car.orientation[i, 0]
+    assert car.left()[i] == car.orientation[i, 1]
+    assert car.up()[i] == car.orientation[i, 2]
+
+hitbox = car.hitbox()
+assert len(hitbox.corners()) == 8
+assert close(hitbox.closest_point(hitbox.center), hitbox.center)
+assert car.touches(Ball(car.time, hitbox.center))
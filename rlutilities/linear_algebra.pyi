@@ -3,6 +3,25 @@ from typing import Tuple, overload
 _Shape = Tuple[int, ...]
 __doc__: str
 
+class vec2:
+    x: float
+    y: float
+
+    @overload
+    def __init__(vec: vec2) -> vec2: ...
+    @overload
+    def __init__(vec: vec3) -> vec2: ...
+    @overload
+    def __init__(vec: tuple[float, float]) -> vec2: ...
+    @overload
+    def __init__(x: float=0, y: float=0) -> vec2: ...
+    def __getitem__(self, i: int) -> float: ...
+    def __setitem__(self, i: int, value: float) -> None: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: vec2) -> bool: ...
+    def __ne__(self, other: vec2) -> bool: ...
+
 class vec3:
     x: float
     y: float
@@ -20,3 +39,17 @@ class vec3:
     def __repr__(self) -> str: ...
     def __eq__(self, other: vec3) -> bool: ...
     def __ne__(self, other: vec3) -> bool: ...
+
+class mat3:
+    @overload
+    def __init__(mat: mat3) -> mat3: ...
+    @overload
+    def __init__(rows: list[list[float]]) -> mat3: ...
+    @overload
+    def __init__(*values: float) -> mat3: ...
+    def __getitem__(self, i: tuple[int, int]) -> float: ...
+    def __setitem__(self, i: tuple[int, int], value: float) -> None: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: mat3) -> bool: ...
+    def __ne__(self, other: mat3) -> bool: ...
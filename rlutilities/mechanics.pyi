@@ -1,7 +1,7 @@
-from typing import Tuple
+from typing import Optional, Tuple, Union, overload
 
-from rlutilities.linear_algebra import vec3
-from rlutilities.simulation import Car, Input
+from rlutilities.linear_algebra import mat3, vec2, vec3
+from rlutilities.simulation import Car, Curve, Input
 
 _Shape = Tuple[int, ...]
 __doc__: str
@@ -16,3 +16,142 @@ class Drive:
     def __init__(car: Car) -> Drive: ...
     def __str__(self) -> str: ...
     def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class Aerial:
+    car: Car
+    arrival_time: float
+    target_position: vec3
+    target_orientation: Optional[mat3]
+    up: vec3
+    double_jump: bool
+    angle_threshold: float
+    reorient_distance: float
+    throttle_distance: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> Aerial: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    @overload
+    def simulate(self) -> Car: ...
+    @overload
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class Reorient:
+    car: Car
+    target_orientation: mat3
+    eps_phi: float
+    eps_omega: float
+    horizon_time: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> Reorient: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class Dodge:
+    car: Car
+    direction: Optional[vec2]
+    preorientation: Optional[mat3]
+    target: Optional[vec3]
+    jump_duration: float
+    delay: Optional[float]
+    timer: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> Dodge: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class Wavedash:
+    car: Car
+    direction: Optional[vec2]
+    timer: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> Wavedash: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class Boostdash:
+    car: Car
+    target: Optional[vec3]
+    timer: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> Boostdash: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class FollowPath:
+    car: Car
+    path: Curve
+    arrival_time: float
+    arrival_speed: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> FollowPath: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+    def calculate_plan(self, path: Curve, arrival_time: float, arrival_speed: float): ...
+
+class Jump:
+    car: Car
+    duration: float
+    double_jump: bool
+    timer: float
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car, duration: float, double_jump: bool=False) -> Jump: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class ReorientML:
+    car: Car
+    target_orientation: mat3
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car) -> ReorientML: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+Mechanic = Union[Aerial, Drive, Reorient, ReorientML, Dodge, Wavedash, Boostdash, FollowPath, Jump, "MechanicSequence", "MechanicParallel"]
+
+class MechanicSequence:
+    car: Car
+    index: int
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car, mechanics: list[Mechanic]) -> MechanicSequence: ...
+    def __len__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
+
+class MechanicParallel:
+    car: Car
+    controls: Input
+    finished: bool
+
+    def __init__(car: Car, mechanics: list[Mechanic]) -> MechanicParallel: ...
+    def __len__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def step(self, dt: float): ...
+    def simulate(self, max_time: float, dt: float=1/120) -> tuple[Car, float]: ...
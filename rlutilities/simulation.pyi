@@ -1,6 +1,9 @@
-from typing import Optional, Tuple, overload
+from typing import Iterator, Optional, Tuple, Union, overload
 
-from rlutilities.linear_algebra import vec3
+import numpy as np
+import numpy.typing as npt
+
+from rlutilities.linear_algebra import mat3, vec3
 
 try:
     from rlbot.utils.structures.game_data_struct import (FieldInfoPacket,
@@ -16,6 +19,7 @@ class Ball:
     position: vec3
     velocity: vec3
     angular_velocity: vec3
+    mode: str
 
     @overload
     def __init__() -> Ball: ...
@@ -26,6 +30,22 @@ class Ball:
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def step(self, dt: float): ...
+    def predict(self, seconds: float, dt: float=1/120) -> BallPrediction: ...
+    def collide_car(self, car: Car) -> Optional[tuple[vec3, vec3]]: ...
+
+def predict_many(balls: list[Ball], seconds: float, dt: float=1/120) -> list[BallPrediction]: ...
+
+class BallPrediction:
+    times: npt.NDArray[np.float32]
+    positions: npt.NDArray[np.float32]
+    velocities: npt.NDArray[np.float32]
+    angular_velocities: npt.NDArray[np.float32]
+
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> Ball: ...
+    def __iter__(self) -> Iterator[Ball]: ...
+    def __str__(self) -> str: ...
+    def at_time(self, time: float) -> Optional[Ball]: ...
 
 class Input:
     steer: float
@@ -41,21 +61,152 @@ class Input:
     def __init__(self) -> Input: ...
     def __str__(self) -> str: ...
 
+class obb:
+    center: vec3
+    half_width: vec3
+    orientation: mat3
+
+    def __init__(center: vec3=vec3(), half_width: vec3=vec3(), orientation: mat3=mat3(1, 0, 0, 0, 1, 0, 0, 0, 1)) -> obb: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def corners(self) -> list[vec3]: ...
+    def closest_point(self, point: vec3) -> vec3: ...
+
+class sphere:
+    center: vec3
+    radius: float
+
+    def __init__(center: vec3=vec3(), radius: float=0) -> sphere: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class ray:
+    start: vec3
+    direction: vec3
+
+    def __init__(start: vec3=vec3(), direction: vec3=vec3()) -> ray: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class CarBody:
+    Octane: CarBody
+    Dominus: CarBody
+    Batmobile: CarBody
+    Breakout: CarBody
+    Venom: CarBody
+    Merc: CarBody
+
+class CarState:
+    OnGround: CarState
+    Jumped: CarState
+    DoubleJumped: CarState
+    Dodged: CarState
+    InAir: CarState
+
 class Car:
     position: vec3
+    velocity: vec3
+    angular_velocity: vec3
+    orientation: mat3
+    supersonic: bool
+    jumped: bool
+    double_jumped: bool
+    on_ground: bool
+    demolished: bool
+    boost: int
+    jump_timer: float
+    dodge_timer: float
+    boost_timer: float
+    enable_jump_acceleration: bool
+    dodge_torque: vec3
+    frame: int
+    time: float
+    body: CarBody
+    state: CarState
+    hitbox_widths: vec3
+    hitbox_offset: vec3
+    team: int
+    id: int
+    controls: Input
+    I: mat3
+    invI: mat3
+    mode: str
 
     def __init__() -> Car: ...
     def __str__(self) -> str: ...
     def step(self, in_: Input, dt: float): ...
+    def forward(self) -> vec3: ...
+    def left(self) -> vec3: ...
+    def up(self) -> vec3: ...
+    def to_local(self, point: vec3) -> vec3: ...
+    def to_world(self, point: vec3) -> vec3: ...
+    def local_angular_velocity(self) -> vec3: ...
+    def hitbox(self) -> obb: ...
+    def closest_point(self, point: vec3) -> vec3: ...
+    def touches(self, ball: Ball) -> bool: ...
+    def simulate(self, inputs: Union[list[Input], Input], dt: float=1/120, steps: Optional[int]=None) -> CarTrajectory: ...
+
+class CarTrajectory:
+    times: npt.NDArray[np.float32]
+    positions: npt.NDArray[np.float32]
+    velocities: npt.NDArray[np.float32]
+    angular_velocities: npt.NDArray[np.float32]
+    orientations: npt.NDArray[np.float32]
+
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> Car: ...
+    def __str__(self) -> str: ...
 
 class Game:
     time_delta: float
+    car_times: npt.NDArray[np.float32]
+    car_positions: npt.NDArray[np.float32]
+    car_velocities: npt.NDArray[np.float32]
+    car_angular_velocities: npt.NDArray[np.float32]
     ball: Ball
     cars: list[Car]
+    mode: str
+    field: Field
 
-    def __init__() -> Game: ...
+    def __init__(mode: Optional[str]=None) -> Game: ...
+    @staticmethod
     def set_mode(mode: str): ...
     def read_field_info(self, field_info: FieldInfoPacket): ...
     def read_packet(self, packet: GameTickPacket): ...
 
-class Field: ...
+class Field:
+    mode: str
+
+    def __init__(mode: Optional[str]=None) -> Field: ...
+    def collide(self, sphere: sphere) -> Optional[ray]: ...
+    def collide_obb(self, obb: obb) -> Optional[ray]: ...
+    def raycast(self, start: vec3, direction: vec3, max_distance: float) -> Optional[tuple[vec3, vec3, float]]: ...
+    def nearest_surface(self, point: vec3) -> Optional[tuple[vec3, vec3, float]]: ...
+
+class ControlPoint:
+    p: vec3
+    t: vec3
+    n: vec3
+
+    def __init__(p: vec3=vec3(), t: vec3=vec3(), n: vec3=vec3()) -> ControlPoint: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class Curve:
+    length: float
+    points: list[vec3]
+    max_speeds: list[float]
+
+    def __init__(control_points: Optional[list[ControlPoint]]=None) -> Curve: ...
+    def __str__(self) -> str: ...
+    def point_at(self, s: float) -> vec3: ...
+    def tangent_at(self, s: float) -> vec3: ...
+    def curvature_at(self, s: float) -> float: ...
+    def find_nearest(self, p: vec3) -> float: ...
+
+class Navigator:
+    car: Car
+
+    def __init__(car: Car) -> Navigator: ...
+    def analyze_surroundings(self, time_budget: float): ...
+    def path_to(self, target: vec3, direction: vec3, speed: float) -> Curve: ...
@@ -0,0 +1,187 @@
+use crate::{
+    base::{FromGil, RemoveGil},
+    mech, mechanic, Car, Input, Mat3, Vec3,
+};
+use pyo3::prelude::*;
+use std::fmt;
+
+#[pyclass]
+pub struct Aerial {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) inner: mech::aerial::Aerial,
+}
+
+impl fmt::Debug for Aerial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Aerial")
+            .field("arrival_time", &self.inner.arrival_time)
+            .field("target_position", &self.inner.target_position)
+            .field("target_orientation", &self.inner.target_orientation)
+            .field("up", &self.inner.up)
+            .field("double_jump", &self.inner.double_jump)
+            .field("controls", &self.inner.controls)
+            .field("finished", &self.inner.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl Aerial {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: mech::aerial::Aerial::default(),
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    /// Runs a copy of the aerial against a copy of the car for up to `max_time` seconds,
+    /// returning the car's final state and the time that elapsed
+    ///
+    /// Without `max_time` it runs rlutilities' own aerial simulation and returns only the final car,
+    /// which is kept for older scripts like `examples/6_Aerial.py`
+    #[pyo3(signature = (max_time=None, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: Option<f32>, dt: f32) -> PyResult<PyObject> {
+        let py = slf.py();
+        let (inner, car) = (slf.inner.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        if let Some(max_time) = max_time {
+            return Ok(mechanic::simulate_car(py, inner, car, dt, max_time)?.into_py(py));
+        }
+
+        let mode = car.get_mode();
+        let car = car.remove_gil(py);
+        let car = py.allow_threads(|| mode.activate(|| inner.simulate(&car)));
+        Ok(Car::from_gil(py, car)?.with_mode(mode).into_py(py))
+    }
+
+    #[inline]
+    #[getter(arrival_time)]
+    fn get_arrival_time(&self) -> f32 {
+        self.inner.arrival_time
+    }
+
+    #[inline]
+    #[setter(arrival_time)]
+    fn set_arrival_time(&mut self, arrival_time: f32) {
+        self.inner.arrival_time = arrival_time;
+    }
+
+    #[inline]
+    #[getter(target_position)]
+    fn get_target_position(&self) -> Vec3 {
+        self.inner.target_position.into()
+    }
+
+    #[inline]
+    #[setter(target_position)]
+    fn set_target_position(&mut self, target_position: Vec3) {
+        self.inner.target_position = target_position.into();
+    }
+
+    #[inline]
+    #[getter(target_orientation)]
+    fn get_target_orientation(&self) -> Option<Mat3> {
+        self.inner.target_orientation.map(Into::into)
+    }
+
+    #[inline]
+    #[setter(target_orientation)]
+    fn set_target_orientation(&mut self, target_orientation: Option<Mat3>) {
+        self.inner.target_orientation = target_orientation.map(Into::into);
+    }
+
+    #[inline]
+    #[getter(up)]
+    fn get_up(&self) -> Vec3 {
+        self.inner.up.into()
+    }
+
+    #[inline]
+    #[setter(up)]
+    fn set_up(&mut self, up: Vec3) {
+        self.inner.up = up.into();
+    }
+
+    #[inline]
+    #[getter(double_jump)]
+    fn get_double_jump(&self) -> bool {
+        self.inner.double_jump
+    }
+
+    #[inline]
+    #[setter(double_jump)]
+    fn set_double_jump(&mut self, double_jump: bool) {
+        self.inner.double_jump = double_jump;
+    }
+
+    #[inline]
+    #[getter(angle_threshold)]
+    fn get_angle_threshold(&self) -> f32 {
+        self.inner.angle_threshold
+    }
+
+    #[inline]
+    #[setter(angle_threshold)]
+    fn set_angle_threshold(&mut self, angle_threshold: f32) {
+        self.inner.angle_threshold = angle_threshold;
+    }
+
+    #[inline]
+    #[getter(reorient_distance)]
+    fn get_reorient_distance(&self) -> f32 {
+        self.inner.reorient_distance
+    }
+
+    #[inline]
+    #[setter(reorient_distance)]
+    fn set_reorient_distance(&mut self, reorient_distance: f32) {
+        self.inner.reorient_distance = reorient_distance;
+    }
+
+    #[inline]
+    #[getter(throttle_distance)]
+    fn get_throttle_distance(&self) -> f32 {
+        self.inner.throttle_distance
+    }
+
+    #[inline]
+    #[setter(throttle_distance)]
+    fn set_throttle_distance(&mut self, throttle_distance: f32) {
+        self.inner.throttle_distance = throttle_distance;
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.inner.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.inner.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
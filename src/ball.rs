@@ -1,4 +1,7 @@
-use crate::{sim, Vec3};
+use crate::{
+    base::{step_count, RemoveGil},
+    sim, ArenaMode, BallPrediction, Car, Vec3,
+};
 use pyo3::{prelude::*, types::PyTuple};
 
 #[pyclass(get_all, set_all)]
@@ -8,6 +11,7 @@ pub struct Ball {
     position: Vec3,
     velocity: Vec3,
     angular_velocity: Vec3,
+    mode: ArenaMode,
 }
 
 impl Default for Ball {
@@ -18,13 +22,14 @@ impl Default for Ball {
             position: Vec3::from([0., 0., 110.]),
             velocity: Vec3::default(),
             angular_velocity: Vec3::default(),
+            mode: ArenaMode::default_mode(),
         }
     }
 }
 
-impl From<sim::ball::Ball> for Ball {
+impl From<Ball> for sim::ball::Ball {
     #[inline]
-    fn from(ball: sim::ball::Ball) -> Self {
+    fn from(ball: Ball) -> Self {
         Self {
             time: ball.time,
             position: ball.position.into(),
@@ -34,14 +39,51 @@ impl From<sim::ball::Ball> for Ball {
     }
 }
 
-impl From<Ball> for sim::ball::Ball {
+impl Ball {
     #[inline]
-    fn from(ball: Ball) -> Self {
+    pub fn from_sim(ball: sim::ball::Ball, mode: ArenaMode) -> Self {
         Self {
             time: ball.time,
             position: ball.position.into(),
             velocity: ball.velocity.into(),
             angular_velocity: ball.angular_velocity.into(),
+            mode,
+        }
+    }
+
+    #[inline]
+    pub fn get_mode(&self) -> ArenaMode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn get_time(&self) -> f32 {
+        self.time
+    }
+
+    #[inline]
+    pub fn get_position(&self) -> Vec3 {
+        self.position
+    }
+
+    #[inline]
+    pub fn get_velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    #[inline]
+    pub fn get_angular_velocity(&self) -> Vec3 {
+        self.angular_velocity
+    }
+
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            time: self.time + (other.time - self.time) * t,
+            position: self.position.lerp(other.position, t),
+            velocity: self.velocity.lerp(other.velocity, t),
+            angular_velocity: self.angular_velocity.lerp(other.angular_velocity, t),
+            mode: self.mode,
         }
     }
 }
@@ -92,17 +134,48 @@ impl Ball {
                 position: vec[0].unwrap_or_default(),
                 velocity: vec[1].unwrap_or_default(),
                 angular_velocity: vec[2].unwrap_or_default(),
+                mode: ArenaMode::default_mode(),
             }
         }
     }
 
-    fn step(&mut self, dt: f32) {
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
         // this code might look like a crime against humanity
         // and I won't deny that but the performance impact is negligible
         // it's well optimized by the compiler and makes syntax cleaner elsewhere
-        let mut ball: sim::ball::Ball = (*self).into();
-        ball.step(dt);
-        *self = ball.into();
+        let this = *slf.try_borrow()?;
+        let mut ball: sim::ball::Ball = this.into();
+        slf.py().allow_threads(|| this.mode.activate(|| ball.step(dt)));
+        *slf.try_borrow_mut()? = Self::from_sim(ball, this.mode);
+
+        Ok(())
+    }
+
+    /// Predicts the ball's path for the next `seconds`, stepping by `dt`
+    ///
+    /// Raises a ValueError if `dt` isn't positive or it would take more than a million steps
+    #[inline]
+    #[pyo3(signature = (seconds, dt=1. / 120.))]
+    fn predict(slf: PyRef<Self>, seconds: f32, dt: f32) -> PyResult<BallPrediction> {
+        let (py, ball) = (slf.py(), *slf);
+        drop(slf);
+
+        let steps = step_count(seconds, dt)?;
+        Ok(py.allow_threads(|| ball.mode.activate(|| BallPrediction::generate(ball, steps, dt))))
+    }
+
+    /// The ball's velocity and angular velocity after a hit from `car`, or `None` if the car isn't touching it
+    ///
+    /// The ball itself isn't changed
+    fn collide_car(slf: PyRef<Self>, car: Car) -> Option<(Vec3, Vec3)> {
+        let (py, this) = (slf.py(), *slf);
+        drop(slf);
+
+        let car = car.remove_gil(py);
+        let mut ball: sim::ball::Ball = this.into();
+        let touched = py.allow_threads(|| this.mode.activate(|| ball.collide(&car)));
+
+        touched.then(|| (ball.velocity.into(), ball.angular_velocity.into()))
     }
 
     #[inline]
@@ -113,11 +186,12 @@ impl Ball {
     #[inline]
     fn __repr__(&self) -> String {
         format!(
-            "Ball(time={}, position={}, velocity={}, angular_velocity={})",
+            "Ball(time={}, position={}, velocity={}, angular_velocity={}, mode={})",
             self.time,
             self.position.__repr__(),
             self.velocity.__repr__(),
-            self.angular_velocity.__repr__()
+            self.angular_velocity.__repr__(),
+            self.mode.as_str()
         )
     }
 }
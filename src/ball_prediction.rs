@@ -0,0 +1,145 @@
+use crate::{
+    base::{step_count, vec3_array},
+    sim, Ball,
+};
+use numpy::{PyArray1, PyArray2};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    prelude::*,
+};
+use rayon::prelude::*;
+
+#[pyclass]
+#[derive(Clone, Debug, Default)]
+pub struct BallPrediction {
+    slices: Vec<Ball>,
+}
+
+impl BallPrediction {
+    /// Steps `ball` forward by `dt` `steps` times, keeping the starting state as the first slice
+    ///
+    /// Get `steps` from `base::step_count`, and the ball's mode must already be active
+    pub fn generate(ball: Ball, steps: usize, dt: f32) -> Self {
+        let mut slices = Vec::with_capacity(steps + 1);
+        slices.push(ball);
+
+        let mode = ball.get_mode();
+        let mut ball: sim::ball::Ball = ball.into();
+        for _ in 0..steps {
+            ball.step(dt);
+            slices.push(Ball::from_sim(ball, mode));
+        }
+
+        Self { slices }
+    }
+}
+
+#[pymethods]
+impl BallPrediction {
+    #[inline]
+    fn __len__(&self) -> usize {
+        self.slices.len()
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<Ball> {
+        let index = if index < 0 { index + self.slices.len() as isize } else { index };
+
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.slices.get(index))
+            .copied()
+            .ok_or_else(|| PyIndexError::new_err("index out of range"))
+    }
+
+    #[inline]
+    fn __iter__(slf: PyRef<Self>) -> BallPredictionIter {
+        BallPredictionIter {
+            slices: slf.slices.clone().into_iter(),
+        }
+    }
+
+    /// The ball's state at time `time`, linearly interpolated between the two closest slices
+    ///
+    /// Returns `None` if `time` is outside of the prediction
+    fn at_time(&self, time: f32) -> Option<Ball> {
+        let (first, last) = (self.slices.first()?, self.slices.last()?);
+        if time < first.get_time() || time > last.get_time() {
+            return None;
+        }
+
+        let i = self.slices.partition_point(|slice| slice.get_time() < time);
+        if i == 0 {
+            return Some(*first);
+        }
+
+        let (prev, next) = (&self.slices[i - 1], &self.slices[i]);
+        let t = (time - prev.get_time()) / (next.get_time() - prev.get_time());
+        Some(prev.lerp(next, t))
+    }
+
+    #[inline]
+    #[getter(times)]
+    fn get_times<'py>(&self, py: Python<'py>) -> &'py PyArray1<f32> {
+        PyArray1::from_iter(py, self.slices.iter().map(Ball::get_time))
+    }
+
+    #[inline]
+    #[getter(positions)]
+    fn get_positions<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.slices.iter().map(|slice| slice.get_position().into()))
+    }
+
+    #[inline]
+    #[getter(velocities)]
+    fn get_velocities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.slices.iter().map(|slice| slice.get_velocity().into()))
+    }
+
+    #[inline]
+    #[getter(angular_velocities)]
+    fn get_angular_velocities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.slices.iter().map(|slice| slice.get_angular_velocity().into()))
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("BallPrediction(slices={})", self.slices.len())
+    }
+}
+
+#[pyclass]
+pub struct BallPredictionIter {
+    slices: std::vec::IntoIter<Ball>,
+}
+
+#[pymethods]
+impl BallPredictionIter {
+    #[inline]
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    #[inline]
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<Ball> {
+        slf.slices.next()
+    }
+}
+
+/// Predicts the path of every ball in `balls` for the next `seconds`, spread across all cores
+///
+/// `seconds` and `dt` are checked the same way as in `Ball.predict`
+#[pyfunction]
+#[pyo3(signature = (balls, seconds, dt=1. / 120.))]
+pub fn predict_many(py: Python, balls: Vec<Ball>, seconds: f32, dt: f32) -> PyResult<Vec<BallPrediction>> {
+    let steps = step_count(seconds, dt)?;
+
+    let Some(mode) = balls.first().map(Ball::get_mode) else {
+        return Ok(Vec::new());
+    };
+
+    if balls.iter().any(|ball| ball.get_mode() != mode) {
+        return Err(PyValueError::new_err("all balls must be in the same mode"));
+    }
+
+    Ok(py.allow_threads(|| mode.activate(|| balls.into_par_iter().map(|ball| BallPrediction::generate(ball, steps, dt)).collect())))
+}
@@ -1,4 +1,35 @@
-use pyo3::{prelude::*, PyClass};
+use numpy::{PyArray1, PyArray2, PyArray3};
+use pyo3::{exceptions::PyValueError, prelude::*, PyClass};
+
+/// The most steps a single simulation call will run, a bit over two hours of game time at 120hz
+pub const MAX_STEPS: usize = 1 << 20;
+
+/// Raises a ValueError unless `dt` is positive and finite
+pub fn check_dt(dt: f32) -> PyResult<()> {
+    if !(dt.is_finite() && dt > 0.) {
+        return Err(PyValueError::new_err(format!("dt must be positive and finite, got {dt}")));
+    }
+
+    Ok(())
+}
+
+/// How many steps of `dt` it takes to cover `duration` seconds
+///
+/// Raises a ValueError unless `dt` is positive, both are finite and it comes to at most `MAX_STEPS`
+pub fn step_count(duration: f32, dt: f32) -> PyResult<usize> {
+    check_dt(dt)?;
+
+    if !(duration.is_finite() && duration >= 0.) {
+        return Err(PyValueError::new_err(format!("duration must be non-negative and finite, got {duration}")));
+    }
+
+    let steps = (duration / dt).round();
+    if steps > MAX_STEPS as f32 {
+        return Err(PyValueError::new_err(format!("{duration} seconds at dt={dt} is more than the {MAX_STEPS} step limit")));
+    }
+
+    Ok(steps as usize)
+}
 
 #[inline]
 pub fn repr_bool(b: bool) -> &'static str {
@@ -81,3 +112,21 @@ where
         U::from_gil(py, self)
     }
 }
+
+/// Packs a list of 3D vectors into a NumPy array of shape (N, 3)
+pub fn vec3_array<'py, I>(py: Python<'py>, vecs: I) -> PyResult<&'py PyArray2<f32>>
+where
+    I: ExactSizeIterator<Item = [f32; 3]>,
+{
+    let len = vecs.len();
+    PyArray1::from_vec(py, vecs.flatten().collect()).reshape([len, 3])
+}
+
+/// Packs a list of row-major 3x3 matrices into a NumPy array of shape (N, 3, 3)
+pub fn mat3_array<'py, I>(py: Python<'py>, mats: I) -> PyResult<&'py PyArray3<f32>>
+where
+    I: ExactSizeIterator<Item = [f32; 9]>,
+{
+    let len = mats.len();
+    PyArray1::from_vec(py, mats.flatten().collect()).reshape([len, 3, 3])
+}
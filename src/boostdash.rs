@@ -0,0 +1,91 @@
+use crate::{mech, mechanic, Car, Input, Vec3};
+use pyo3::prelude::*;
+use std::fmt;
+
+#[pyclass]
+pub struct Boostdash {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) inner: mech::boostdash::Boostdash,
+}
+
+impl fmt::Debug for Boostdash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Boostdash")
+            .field("target", &self.inner.target)
+            .field("timer", &self.inner.timer)
+            .field("controls", &self.inner.controls)
+            .field("finished", &self.inner.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl Boostdash {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: mech::boostdash::Boostdash::default(),
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (inner, car) = (slf.inner.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, inner, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(target)]
+    fn get_target(&self) -> Option<Vec3> {
+        self.inner.target.map(Into::into)
+    }
+
+    #[inline]
+    #[setter(target)]
+    fn set_target(&mut self, target: Option<Vec3>) {
+        self.inner.target = target.map(Into::into);
+    }
+
+    #[inline]
+    #[getter(timer)]
+    fn get_timer(&self) -> f32 {
+        self.inner.timer
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.inner.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.inner.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
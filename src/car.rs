@@ -1,11 +1,93 @@
 use crate::{
-    base::{FromGil, RemoveGil, PyDefault},
-    sim, Input, Mat3, Vec3, new_gil_default, new_gil,
+    base::{check_dt, FromGil, PyDefault, RemoveGil, MAX_STEPS},
+    new_gil, new_gil_default, sim, ArenaMode, Ball, CarTrajectory, Input, Mat3, Obb, Vec3,
 };
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+#[derive(FromPyObject)]
+enum Inputs {
+    Many(Vec<Input>),
+    One(Input),
+}
 
 #[pyclass]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CarBody {
+    Octane,
+    Dominus,
+    Batmobile,
+    Breakout,
+    Venom,
+    Merc,
+}
+
+impl From<sim::car::CarBody> for CarBody {
+    #[inline]
+    fn from(body: sim::car::CarBody) -> Self {
+        match body {
+            sim::car::CarBody::Octane => Self::Octane,
+            sim::car::CarBody::Dominus => Self::Dominus,
+            sim::car::CarBody::Batmobile => Self::Batmobile,
+            sim::car::CarBody::Breakout => Self::Breakout,
+            sim::car::CarBody::Venom => Self::Venom,
+            sim::car::CarBody::Merc => Self::Merc,
+        }
+    }
+}
+
+impl From<CarBody> for sim::car::CarBody {
+    #[inline]
+    fn from(body: CarBody) -> Self {
+        match body {
+            CarBody::Octane => Self::Octane,
+            CarBody::Dominus => Self::Dominus,
+            CarBody::Batmobile => Self::Batmobile,
+            CarBody::Breakout => Self::Breakout,
+            CarBody::Venom => Self::Venom,
+            CarBody::Merc => Self::Merc,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CarState {
+    OnGround,
+    Jumped,
+    DoubleJumped,
+    Dodged,
+    InAir,
+}
+
+impl From<sim::car::CarState> for CarState {
+    #[inline]
+    fn from(state: sim::car::CarState) -> Self {
+        match state {
+            sim::car::CarState::OnGround => Self::OnGround,
+            sim::car::CarState::Jumped => Self::Jumped,
+            sim::car::CarState::DoubleJumped => Self::DoubleJumped,
+            sim::car::CarState::Dodged => Self::Dodged,
+            sim::car::CarState::InAir => Self::InAir,
+        }
+    }
+}
+
+impl From<CarState> for sim::car::CarState {
+    #[inline]
+    fn from(state: CarState) -> Self {
+        match state {
+            CarState::OnGround => Self::OnGround,
+            CarState::Jumped => Self::Jumped,
+            CarState::DoubleJumped => Self::DoubleJumped,
+            CarState::Dodged => Self::Dodged,
+            CarState::InAir => Self::InAir,
+        }
+    }
+}
+
+#[pyclass(get_all, set_all)]
 #[derive(Clone, Debug)]
+#[allow(non_snake_case)]
 pub struct Car {
     position: Py<Vec3>,
     velocity: Py<Vec3>,
@@ -24,8 +106,8 @@ pub struct Car {
     dodge_torque: Py<Vec3>,
     frame: i32,
     time: f32,
-    body: sim::car::CarBody,
-    state: sim::car::CarState,
+    body: CarBody,
+    state: CarState,
     hitbox_widths: Py<Vec3>,
     hitbox_offset: Py<Vec3>,
     team: i32,
@@ -33,6 +115,7 @@ pub struct Car {
     controls: Py<Input>,
     I: Py<Mat3>,
     invI: Py<Mat3>,
+    mode: ArenaMode,
 }
 
 impl PyDefault for Car {
@@ -56,8 +139,8 @@ impl PyDefault for Car {
             dodge_torque: new_gil_default!(Vec3, py),
             frame: 0,
             time: 0.,
-            body: sim::car::CarBody::Octane,
-            state: sim::car::CarState::OnGround,
+            body: CarBody::Octane,
+            state: CarState::OnGround,
             hitbox_widths: new_gil_default!(Vec3, py),
             hitbox_offset: new_gil_default!(Vec3, py),
             team: 0,
@@ -65,6 +148,7 @@ impl PyDefault for Car {
             controls: new_gil_default!(Input, py),
             I: new_gil_default!(Mat3, py),
             invI: new_gil_default!(Mat3, py),
+            mode: ArenaMode::default_mode(),
         })
     }
 }
@@ -90,8 +174,8 @@ impl RemoveGil<sim::car::Car> for Car {
             dodge_torque: self.dodge_torque.remove_gil(py),
             frame: self.frame,
             time: self.time,
-            body: self.body,
-            state: self.state,
+            body: self.body.into(),
+            state: self.state.into(),
             hitbox_widths: self.hitbox_widths.remove_gil(py),
             hitbox_offset: self.hitbox_offset.remove_gil(py),
             team: self.team,
@@ -110,10 +194,56 @@ impl FromGil<sim::car::Car> for Car {
             position: new_gil!(Vec3, py, car.position),
             velocity: new_gil!(Vec3, py, car.velocity),
             angular_velocity: new_gil!(Vec3, py, car.angular_velocity),
+            orientation: new_gil!(Mat3, py, car.orientation),
+            supersonic: car.supersonic,
+            jumped: car.jumped,
+            double_jumped: car.double_jumped,
+            on_ground: car.on_ground,
+            demolished: car.demolished,
+            boost: car.boost,
+            jump_timer: car.jump_timer,
+            dodge_timer: car.dodge_timer,
+            boost_timer: car.boost_timer,
+            enable_jump_acceleration: car.enable_jump_acceleration,
+            dodge_torque: new_gil!(Vec3, py, car.dodge_torque),
+            frame: car.frame,
+            time: car.time,
+            body: car.body.into(),
+            state: car.state.into(),
+            hitbox_widths: new_gil!(Vec3, py, car.hitbox_widths),
+            hitbox_offset: new_gil!(Vec3, py, car.hitbox_offset),
+            team: car.team,
+            id: car.id,
+            controls: new_gil!(Input, py, car.controls),
+            I: new_gil!(Mat3, py, car.I),
+            invI: new_gil!(Mat3, py, car.invI),
+            mode: ArenaMode::default_mode(),
         })
     }
 }
 
+impl Car {
+    #[inline]
+    pub fn get_mode(&self) -> ArenaMode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn with_mode(mut self, mode: ArenaMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Replaces this object's state with `car`, keeping its mode
+    ///
+    /// Every vector and matrix is a new Python object, so values the caller got from
+    /// this car before (like `car.position`) keep the old state instead of changing under them
+    pub fn update(&mut self, py: Python, car: sim::car::Car) -> PyResult<()> {
+        *self = Self::from_gil(py, car)?.with_mode(self.mode);
+        Ok(())
+    }
+}
+
 #[pymethods]
 impl Car {
     #[new]
@@ -123,8 +253,114 @@ impl Car {
     }
 
     #[inline]
-    fn step(&mut self, py: Python, in_: Input, dt: f32) {
-        self.remove_gil(py).step(in_.into(), dt);
+    fn step(slf: &PyCell<Self>, in_: Input, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let this = slf.try_borrow()?.clone();
+        let mode = this.mode;
+        let mut car = this.remove_gil(py);
+        py.allow_threads(|| mode.activate(|| car.step(in_.into(), dt)));
+        slf.try_borrow_mut()?.update(py, car)
+    }
+
+    #[inline]
+    fn forward(&self, py: Python) -> Vec3 {
+        self.orientation.borrow(py).column(0)
+    }
+
+    #[inline]
+    fn left(&self, py: Python) -> Vec3 {
+        self.orientation.borrow(py).column(1)
+    }
+
+    #[inline]
+    fn up(&self, py: Python) -> Vec3 {
+        self.orientation.borrow(py).column(2)
+    }
+
+    /// Converts a point in world coordinates to coordinates relative to the car
+    #[inline]
+    fn to_local(&self, py: Python, point: Vec3) -> Vec3 {
+        self.orientation.borrow(py).transpose_dot(point - *self.position.borrow(py))
+    }
+
+    /// Converts a point relative to the car to world coordinates
+    #[inline]
+    fn to_world(&self, py: Python, point: Vec3) -> Vec3 {
+        *self.position.borrow(py) + self.orientation.borrow(py).dot(point)
+    }
+
+    /// The angular velocity in the car's (forward, left, up) frame
+    #[inline]
+    fn local_angular_velocity(&self, py: Python) -> Vec3 {
+        self.orientation.borrow(py).transpose_dot(*self.angular_velocity.borrow(py))
+    }
+
+    #[inline]
+    fn hitbox(&self, py: Python) -> Obb {
+        self.clone().remove_gil(py).hitbox().into()
+    }
+
+    /// The point in or on the car's hitbox that is closest to `point`
+    #[inline]
+    fn closest_point(&self, py: Python, point: Vec3) -> Vec3 {
+        self.hitbox(py).closest_point(point)
+    }
+
+    /// Whether the ball is touching or overlapping the car's hitbox
+    fn touches(slf: PyRef<Self>, ball: Ball) -> bool {
+        let py = slf.py();
+        let hitbox = slf.hitbox(py);
+        drop(slf);
+
+        let mode = ball.get_mode();
+        let sphere = py.allow_threads(|| mode.activate(|| sim::ball::Ball::from(ball).hitbox()));
+        let center = sphere.center.into();
+        let offset: [f32; 3] = (hitbox.closest_point(center) - center).into();
+
+        offset.iter().map(|x| x * x).sum::<f32>() <= sphere.radius * sphere.radius
+    }
+
+    /// Steps a copy of the car under `inputs` and returns its state after every step as a `CarTrajectory`
+    ///
+    /// A list of inputs is applied one per step, a single input is held for all of them
+    ///
+    /// Raises a ValueError if `dt` isn't positive and finite or there are more than a million steps
+    #[pyo3(signature = (inputs, dt=1. / 120., steps=None))]
+    fn simulate(slf: PyRef<Self>, inputs: Inputs, dt: f32, steps: Option<usize>) -> PyResult<CarTrajectory> {
+        check_dt(dt)?;
+        if steps.is_some_and(|steps| steps > MAX_STEPS) {
+            return Err(PyValueError::new_err(format!("steps can be at most {MAX_STEPS}")));
+        }
+
+        let inputs: Vec<sim::input::Input> = match (inputs, steps) {
+            (Inputs::Many(inputs), None) => inputs.into_iter().map(Into::into).collect(),
+            (Inputs::Many(inputs), Some(steps)) if inputs.len() == steps => inputs.into_iter().map(Into::into).collect(),
+            (Inputs::Many(inputs), Some(steps)) => {
+                return Err(PyValueError::new_err(format!("got {} inputs for {steps} steps", inputs.len())));
+            }
+            (Inputs::One(input), Some(steps)) => vec![input.into(); steps],
+            (Inputs::One(_), None) => return Err(PyValueError::new_err("steps is required when a single input is given")),
+        };
+
+        let py = slf.py();
+        let mode = slf.mode;
+        let mut car = slf.clone().remove_gil(py);
+        drop(slf);
+
+        let states = py.allow_threads(|| {
+            mode.activate(|| {
+                let mut states = Vec::with_capacity(inputs.len());
+
+                for input in inputs {
+                    car.step(input, dt);
+                    states.push(car.clone());
+                }
+
+                states
+            })
+        });
+
+        Ok(CarTrajectory::new(states, mode))
     }
 
     #[inline]
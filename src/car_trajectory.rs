@@ -0,0 +1,76 @@
+use crate::{
+    base::{mat3_array, vec3_array, FromGil},
+    sim, ArenaMode, Car,
+};
+use numpy::{PyArray1, PyArray2, PyArray3};
+use pyo3::{exceptions::PyIndexError, prelude::*};
+
+/// The states of a car from `Car.simulate`, one per step
+///
+/// The arrays are built straight from the states, `Car` objects are only made when indexing
+#[pyclass]
+pub struct CarTrajectory {
+    states: Vec<sim::car::Car>,
+    mode: ArenaMode,
+}
+
+impl CarTrajectory {
+    #[inline]
+    pub fn new(states: Vec<sim::car::Car>, mode: ArenaMode) -> Self {
+        Self { states, mode }
+    }
+}
+
+#[pymethods]
+impl CarTrajectory {
+    #[inline]
+    fn __len__(&self) -> usize {
+        self.states.len()
+    }
+
+    fn __getitem__(&self, py: Python, index: isize) -> PyResult<Car> {
+        let index = if index < 0 { index + self.states.len() as isize } else { index };
+
+        let state = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.states.get(index))
+            .ok_or_else(|| PyIndexError::new_err("index out of range"))?;
+
+        Ok(Car::from_gil(py, state.clone())?.with_mode(self.mode))
+    }
+
+    #[inline]
+    #[getter(times)]
+    fn get_times<'py>(&self, py: Python<'py>) -> &'py PyArray1<f32> {
+        PyArray1::from_iter(py, self.states.iter().map(|car| car.time))
+    }
+
+    #[inline]
+    #[getter(positions)]
+    fn get_positions<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.states.iter().map(|car| car.position.data))
+    }
+
+    #[inline]
+    #[getter(velocities)]
+    fn get_velocities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.states.iter().map(|car| car.velocity.data))
+    }
+
+    #[inline]
+    #[getter(angular_velocities)]
+    fn get_angular_velocities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.states.iter().map(|car| car.angular_velocity.data))
+    }
+
+    #[inline]
+    #[getter(orientations)]
+    fn get_orientations<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray3<f32>> {
+        mat3_array(py, self.states.iter().map(|car| car.orientation.data))
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("CarTrajectory(states={})", self.states.len())
+    }
+}
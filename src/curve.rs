@@ -0,0 +1,154 @@
+use crate::{sim, Vec3};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+#[pyclass(get_all, set_all)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ControlPoint {
+    p: Vec3,
+    t: Vec3,
+    n: Vec3,
+}
+
+impl From<sim::curve::ControlPoint> for ControlPoint {
+    #[inline]
+    fn from(point: sim::curve::ControlPoint) -> Self {
+        Self {
+            p: point.p.into(),
+            t: point.t.into(),
+            n: point.n.into(),
+        }
+    }
+}
+
+impl From<ControlPoint> for sim::curve::ControlPoint {
+    #[inline]
+    fn from(point: ControlPoint) -> Self {
+        Self {
+            p: point.p.into(),
+            t: point.t.into(),
+            n: point.n.into(),
+        }
+    }
+}
+
+#[pymethods]
+impl ControlPoint {
+    #[new]
+    #[pyo3(signature = (p=Vec3::default(), t=Vec3::default(), n=Vec3::default()))]
+    fn __new__(p: Vec3, t: Vec3, n: Vec3) -> Self {
+        Self { p, t, n }
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[inline]
+    fn __repr__(&self) -> String {
+        format!("ControlPoint(p={}, t={}, n={})", self.p.__repr__(), self.t.__repr__(), self.n.__repr__())
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Default)]
+#[repr(transparent)]
+pub struct Curve(sim::curve::Curve);
+
+impl From<sim::curve::Curve> for Curve {
+    #[inline]
+    fn from(curve: sim::curve::Curve) -> Self {
+        Self(curve)
+    }
+}
+
+impl From<Curve> for sim::curve::Curve {
+    #[inline]
+    fn from(curve: Curve) -> Self {
+        curve.0
+    }
+}
+
+impl Curve {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.points.is_empty()
+    }
+
+    /// The underlying curve, or a ValueError if there's nothing to query
+    fn non_empty(&self) -> PyResult<&sim::curve::Curve> {
+        if self.is_empty() {
+            return Err(PyValueError::new_err("the curve is empty"));
+        }
+
+        Ok(&self.0)
+    }
+}
+
+#[pymethods]
+impl Curve {
+    /// Builds a curve through `control_points`, which needs at least 2 of them
+    ///
+    /// Without any control points the curve is empty and can't be queried
+    #[new]
+    #[pyo3(signature = (control_points=None))]
+    fn __new__(control_points: Option<Vec<ControlPoint>>) -> PyResult<Self> {
+        let Some(control_points) = control_points else {
+            return Ok(Self::default());
+        };
+
+        if control_points.len() < 2 {
+            return Err(PyValueError::new_err(format!("a curve needs at least 2 control points, got {}", control_points.len())));
+        }
+
+        let control_points: Vec<sim::curve::ControlPoint> = control_points.into_iter().map(Into::into).collect();
+        Ok(Self(sim::curve::Curve::new(&control_points)))
+    }
+
+    #[inline]
+    #[getter(length)]
+    fn get_length(&self) -> f32 {
+        self.0.length
+    }
+
+    #[inline]
+    #[getter(points)]
+    fn get_points(&self) -> Vec<Vec3> {
+        self.0.points.iter().copied().map(Into::into).collect()
+    }
+
+    #[inline]
+    #[getter(max_speeds)]
+    fn get_max_speeds(&self) -> Vec<f32> {
+        self.0.max_speeds.clone()
+    }
+
+    /// The point on the curve that is `s` units of arc length away from the end of the curve
+    #[inline]
+    fn point_at(&self, s: f32) -> PyResult<Vec3> {
+        Ok(self.non_empty()?.point_at(s).into())
+    }
+
+    /// The unit tangent of the curve at `s` units of arc length away from the end of the curve
+    #[inline]
+    fn tangent_at(&self, s: f32) -> PyResult<Vec3> {
+        Ok(self.non_empty()?.tangent_at(s).into())
+    }
+
+    /// The curvature of the curve at `s` units of arc length away from the end of the curve
+    #[inline]
+    fn curvature_at(&self, s: f32) -> PyResult<f32> {
+        Ok(self.non_empty()?.curvature_at(s))
+    }
+
+    /// The arc length parameter `s` of the point on the curve that is closest to `p`
+    #[inline]
+    fn find_nearest(&self, p: Vec3) -> PyResult<f32> {
+        Ok(self.non_empty()?.find_nearest(p.into()))
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("Curve(length={}, points={})", self.0.length, self.0.points.len())
+    }
+}
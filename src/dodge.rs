@@ -0,0 +1,143 @@
+use crate::{mech, mechanic, Car, Input, Mat3, Vec2, Vec3};
+use pyo3::prelude::*;
+use std::fmt;
+
+#[pyclass]
+pub struct Dodge {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) inner: mech::dodge::Dodge,
+}
+
+impl fmt::Debug for Dodge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dodge")
+            .field("direction", &self.inner.direction)
+            .field("preorientation", &self.inner.preorientation)
+            .field("target", &self.inner.target)
+            .field("jump_duration", &self.inner.jump_duration)
+            .field("delay", &self.inner.delay)
+            .field("timer", &self.inner.timer)
+            .field("controls", &self.inner.controls)
+            .field("finished", &self.inner.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl Dodge {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: mech::dodge::Dodge::default(),
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (inner, car) = (slf.inner.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, inner, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(direction)]
+    fn get_direction(&self) -> Option<Vec2> {
+        self.inner.direction.map(Into::into)
+    }
+
+    #[inline]
+    #[setter(direction)]
+    fn set_direction(&mut self, direction: Option<Vec2>) {
+        self.inner.direction = direction.map(Into::into);
+    }
+
+    #[inline]
+    #[getter(preorientation)]
+    fn get_preorientation(&self) -> Option<Mat3> {
+        self.inner.preorientation.map(Into::into)
+    }
+
+    #[inline]
+    #[setter(preorientation)]
+    fn set_preorientation(&mut self, preorientation: Option<Mat3>) {
+        self.inner.preorientation = preorientation.map(Into::into);
+    }
+
+    #[inline]
+    #[getter(target)]
+    fn get_target(&self) -> Option<Vec3> {
+        self.inner.target.map(Into::into)
+    }
+
+    #[inline]
+    #[setter(target)]
+    fn set_target(&mut self, target: Option<Vec3>) {
+        self.inner.target = target.map(Into::into);
+    }
+
+    #[inline]
+    #[getter(jump_duration)]
+    fn get_jump_duration(&self) -> f32 {
+        self.inner.jump_duration
+    }
+
+    #[inline]
+    #[setter(jump_duration)]
+    fn set_jump_duration(&mut self, jump_duration: f32) {
+        self.inner.jump_duration = jump_duration;
+    }
+
+    #[inline]
+    #[getter(delay)]
+    fn get_delay(&self) -> Option<f32> {
+        self.inner.delay
+    }
+
+    #[inline]
+    #[setter(delay)]
+    fn set_delay(&mut self, delay: Option<f32>) {
+        self.inner.delay = delay;
+    }
+
+    #[inline]
+    #[getter(timer)]
+    fn get_timer(&self) -> f32 {
+        self.inner.timer
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.inner.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.inner.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{mech, Car, Input, Vec3};
+use crate::{mech, mechanic, Car, Input, Vec3};
 use pyo3::prelude::*;
 use std::fmt;
 
@@ -6,7 +6,7 @@ use std::fmt;
 pub struct Drive {
     #[pyo3(get, set)]
     car: Py<Car>,
-    inner: mech::drive::Drive,
+    pub(crate) inner: mech::drive::Drive,
 }
 
 impl fmt::Debug for Drive {
@@ -32,8 +32,26 @@ impl Drive {
     }
 
     #[inline]
-    fn step(&mut self, py: Python, dt: f32) {
-        self.inner.step(&self.car.borrow(py).into(), dt);
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (inner, car) = (slf.inner.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, inner, car, dt, max_time)
     }
 
     #[inline]
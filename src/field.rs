@@ -1,4 +1,153 @@
-use pyo3::prelude::*;
+use crate::{sim, ArenaMode, Obb, Ray, Sphere, Vec3};
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 #[pyclass]
-pub struct Field();
+#[repr(transparent)]
+pub struct Field(ArenaMode);
+
+impl From<ArenaMode> for Field {
+    #[inline]
+    fn from(mode: ArenaMode) -> Self {
+        Self(mode)
+    }
+}
+
+/// The largest distance `nearest_surface` will look for a surface at
+const MAX_SURFACE_DISTANCE: f32 = 16384.;
+
+/// Binary search iterations used by `nearest_surface`, enough for sub-unit precision
+const SURFACE_SEARCH_ITERATIONS: usize = 24;
+
+/// rlutilities_rs reports "no contact" as a ray with a zero-length direction
+#[inline]
+fn contact(ray: sim::geometry::Ray) -> Option<Ray> {
+    (ray.direction.data != [0.; 3]).then(|| ray.into())
+}
+
+#[pymethods]
+impl Field {
+    #[new]
+    #[inline]
+    #[pyo3(signature = (mode=None))]
+    fn __new__(mode: Option<ArenaMode>) -> Self {
+        Self(mode.unwrap_or_else(ArenaMode::default_mode))
+    }
+
+    #[inline]
+    #[getter(mode)]
+    fn get_mode(&self) -> ArenaMode {
+        self.0
+    }
+
+    #[inline]
+    #[setter(mode)]
+    fn set_mode(&mut self, mode: ArenaMode) {
+        self.0 = mode;
+    }
+
+    /// The contact point (`start`) and surface normal (`direction`) where the sphere
+    /// overlaps the arena, or `None` if it doesn't
+    #[inline]
+    fn collide(slf: PyRef<Self>, sphere: Sphere) -> Option<Ray> {
+        let (py, mode) = (slf.py(), slf.0);
+        drop(slf);
+
+        let sphere = sphere.into();
+        contact(py.allow_threads(|| mode.activate(|| sim::field::Field::collide_sphere(&sphere))))
+    }
+
+    /// The contact point (`start`) and surface normal (`direction`) where the box
+    /// overlaps the arena, or `None` if it doesn't
+    #[inline]
+    fn collide_obb(slf: PyRef<Self>, obb: Obb) -> Option<Ray> {
+        let (py, mode) = (slf.py(), slf.0);
+        drop(slf);
+
+        let obb = obb.into();
+        contact(py.allow_threads(|| mode.activate(|| sim::field::Field::collide_obb(&obb))))
+    }
+
+    /// Casts a ray from `start` along `direction` against the arena mesh
+    ///
+    /// Returns the hit point, the surface normal and the distance to the hit,
+    /// or `None` if nothing is hit within `max_distance`
+    ///
+    /// Raises a ValueError unless `direction` is finite and non-zero and `max_distance` is finite and positive
+    fn raycast(slf: PyRef<Self>, start: Vec3, direction: Vec3, max_distance: f32) -> PyResult<Option<(Vec3, Vec3, f32)>> {
+        let (py, mode) = (slf.py(), slf.0);
+        drop(slf);
+
+        let length = direction.norm();
+        if !(length.is_finite() && length > 0.) {
+            return Err(PyValueError::new_err("direction must be finite and non-zero"));
+        }
+
+        if !(max_distance.is_finite() && max_distance > 0.) {
+            return Err(PyValueError::new_err(format!("max_distance must be positive and finite, got {max_distance}")));
+        }
+
+        let ray = Ray::new(start, direction * (max_distance / length)).into();
+        let Some(hit) = contact(py.allow_threads(|| mode.activate(|| sim::field::Field::raycast_any(&ray)))) else {
+            return Ok(None);
+        };
+        let (point, normal) = (hit.get_start(), hit.get_direction());
+
+        Ok(Some((point, normal, (point - start).norm())))
+    }
+
+    /// The closest point on the arena to `point`, the surface normal there,
+    /// and the signed distance to it - positive inside the arena and negative outside
+    ///
+    /// Returns `None` if there's no surface within 16384 units, or if the surface's own normal
+    /// can't be found to tell which side of it `point` is on
+    fn nearest_surface(slf: PyRef<Self>, point: Vec3) -> Option<(Vec3, Vec3, f32)> {
+        let (py, mode) = (slf.py(), slf.0);
+        drop(slf);
+
+        let collide = |radius: f32| contact(sim::field::Field::collide_sphere(&Sphere::new(point, radius).into()));
+
+        py.allow_threads(|| {
+            mode.activate(|| {
+                // find a sphere that's big enough to reach the arena, then shrink it until it barely touches
+                let mut hi = 1.;
+                let mut hit = loop {
+                    if let Some(hit) = collide(hi) {
+                        break hit;
+                    }
+
+                    hi *= 2.;
+                    if hi > MAX_SURFACE_DISTANCE {
+                        return None;
+                    }
+                };
+
+                let mut lo = 0.;
+                for _ in 0..SURFACE_SEARCH_ITERATIONS {
+                    let mid = (lo + hi) / 2.;
+                    match collide(mid) {
+                        Some(mid_hit) => {
+                            hi = mid;
+                            hit = mid_hit;
+                        }
+                        None => lo = mid,
+                    }
+                }
+
+                let surface = hit.get_start();
+                let to_contact = surface - point;
+                let distance = to_contact.norm();
+                if distance == 0. {
+                    return Some((surface, hit.get_direction(), 0.));
+                }
+
+                // the sphere contact normal always points back at `point`, so cast a ray at the contact
+                // to get the surface's own normal - near an edge the closest point isn't straight along it,
+                // so it only gives the sign and the distance is still the full length to `point`
+                let ray = Ray::new(point, to_contact * ((distance + 1.) / distance)).into();
+                let normal = contact(sim::field::Field::raycast_any(&ray))?.get_direction();
+
+                Some((surface, normal, distance.copysign((point - surface).dot(normal))))
+            })
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{base::RemoveGil, mech, mechanic, Car, Curve, Input};
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+};
+use std::fmt;
+
+#[pyclass]
+pub struct FollowPath {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) inner: mech::follow_path::FollowPath,
+    planned: bool,
+}
+
+impl FollowPath {
+    /// The mechanic, or a RuntimeError if `calculate_plan` hasn't been called for the current path
+    pub(crate) fn planned(&self) -> PyResult<&mech::follow_path::FollowPath> {
+        if !self.planned {
+            return Err(PyRuntimeError::new_err("calculate_plan must be called before following the path"));
+        }
+
+        Ok(&self.inner)
+    }
+}
+
+impl fmt::Debug for FollowPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FollowPath")
+            .field("arrival_time", &self.inner.arrival_time)
+            .field("arrival_speed", &self.inner.arrival_speed)
+            .field("controls", &self.inner.controls)
+            .field("finished", &self.inner.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl FollowPath {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: mech::follow_path::FollowPath::default(),
+            planned: false,
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.planned()?.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (inner, car) = (slf.planned()?.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, inner, car, dt, max_time)
+    }
+
+    /// Sets the path to follow and calculates the speed plan needed to reach
+    /// the end of it at `arrival_time` while moving at `arrival_speed`
+    fn calculate_plan(slf: &PyCell<Self>, path: Curve, arrival_time: f32, arrival_speed: f32) -> PyResult<()> {
+        if path.is_empty() {
+            return Err(PyValueError::new_err("can't plan along an empty curve"));
+        }
+
+        let py = slf.py();
+        let (mut inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.borrow(py).clone())
+        };
+
+        let mode = car.get_mode();
+        let car = car.remove_gil(py);
+        py.allow_threads(|| mode.activate(|| inner.calculate_plan(&car, path.into(), arrival_time, arrival_speed)));
+
+        let mut this = slf.try_borrow_mut()?;
+        this.inner = inner;
+        this.planned = true;
+
+        Ok(())
+    }
+
+    #[inline]
+    #[getter(path)]
+    fn get_path(&self) -> Curve {
+        self.inner.path.clone().into()
+    }
+
+    /// Replaces the path without recalculating the plan, `calculate_plan` must be called again before use
+    #[inline]
+    #[setter(path)]
+    fn set_path(&mut self, path: Curve) {
+        self.inner.path = path.into();
+        self.planned = false;
+    }
+
+    #[inline]
+    #[getter(arrival_time)]
+    fn get_arrival_time(&self) -> f32 {
+        self.inner.arrival_time
+    }
+
+    #[inline]
+    #[setter(arrival_time)]
+    fn set_arrival_time(&mut self, arrival_time: f32) {
+        self.inner.arrival_time = arrival_time;
+    }
+
+    #[inline]
+    #[getter(arrival_speed)]
+    fn get_arrival_speed(&self) -> f32 {
+        self.inner.arrival_speed
+    }
+
+    #[inline]
+    #[setter(arrival_speed)]
+    fn set_arrival_speed(&mut self, arrival_speed: f32) {
+        self.inner.arrival_speed = arrival_speed;
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.inner.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.inner.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
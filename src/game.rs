@@ -1,10 +1,13 @@
-use crate::{linalg::math, sim, Ball, Car, FieldInfoPacket, GameTickPacket};
+use crate::{
+    base::{vec3_array, FromGil},
+    linalg::math,
+    sim, ArenaMode, Ball, Car, Field, FieldInfoPacket, GameTickPacket,
+};
+use numpy::{PyArray1, PyArray2};
 use pyo3::prelude::*;
 
 #[pyclass]
-#[derive(Default)]
-#[repr(transparent)]
-pub struct Game(sim::game::Game);
+pub struct Game(sim::game::Game, ArenaMode);
 
 impl Game {
     #[inline]
@@ -27,14 +30,35 @@ impl Game {
 impl Game {
     #[new]
     #[inline]
-    fn __new__() -> Self {
-        Self::default()
+    #[pyo3(signature = (mode=None))]
+    fn __new__(mode: Option<ArenaMode>) -> Self {
+        Self(sim::game::Game::default(), mode.unwrap_or_else(ArenaMode::default_mode))
     }
 
+    /// Sets the mode used by any `Game`, `Ball`, `Car` or `Field` that's created without one and loads its arena
     #[inline]
     #[staticmethod]
-    fn set_mode(mode: String) {
-        sim::game::Game::set_mode(mode);
+    fn set_mode(py: Python, mode: ArenaMode) {
+        mode.set_default_mode();
+        py.allow_threads(|| mode.load());
+    }
+
+    #[inline]
+    #[getter(mode)]
+    fn get_mode(&self) -> ArenaMode {
+        self.1
+    }
+
+    #[inline]
+    #[setter(mode)]
+    fn set_instance_mode(&mut self, mode: ArenaMode) {
+        self.1 = mode;
+    }
+
+    #[inline]
+    #[getter(field)]
+    fn get_field(&self) -> Field {
+        Field::from(self.1)
     }
 
     fn read_field_info(&mut self, field_info: FieldInfoPacket) {
@@ -116,7 +140,7 @@ impl Game {
     #[inline]
     #[getter(ball)]
     fn get_ball(&self) -> Ball {
-        self.0.ball.into()
+        Ball::from_sim(self.0.ball, self.1)
     }
 
     #[inline]
@@ -127,8 +151,32 @@ impl Game {
 
     #[inline]
     #[getter(cars)]
-    fn get_cars(&self) -> Vec<Car> {
-        self.0.cars.iter().cloned().map(Into::into).collect()
+    fn get_cars(&self, py: Python) -> PyResult<Vec<Car>> {
+        self.0.cars.iter().cloned().map(|car| Ok(Car::from_gil(py, car)?.with_mode(self.1))).collect()
+    }
+
+    #[inline]
+    #[getter(car_times)]
+    fn get_car_times<'py>(&self, py: Python<'py>) -> &'py PyArray1<f32> {
+        PyArray1::from_iter(py, self.0.cars.iter().map(|car| car.time))
+    }
+
+    #[inline]
+    #[getter(car_positions)]
+    fn get_car_positions<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.0.cars.iter().map(|car| car.position.data))
+    }
+
+    #[inline]
+    #[getter(car_velocities)]
+    fn get_car_velocities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.0.cars.iter().map(|car| car.velocity.data))
+    }
+
+    #[inline]
+    #[getter(car_angular_velocities)]
+    fn get_car_angular_velocities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        vec3_array(py, self.0.cars.iter().map(|car| car.angular_velocity.data))
     }
 
     #[inline]
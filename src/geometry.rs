@@ -0,0 +1,206 @@
+use crate::{sim, Mat3, Vec3};
+use pyo3::prelude::*;
+
+#[pyclass(get_all, set_all)]
+#[derive(Clone, Copy, Debug)]
+#[pyo3(name = "obb")]
+pub struct Obb {
+    center: Vec3,
+    half_width: Vec3,
+    orientation: Mat3,
+}
+
+impl Default for Obb {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            center: Vec3::default(),
+            half_width: Vec3::default(),
+            orientation: Mat3::IDENTITY,
+        }
+    }
+}
+
+impl From<sim::geometry::Obb> for Obb {
+    #[inline]
+    fn from(obb: sim::geometry::Obb) -> Self {
+        Self {
+            center: obb.center.into(),
+            half_width: obb.half_width.into(),
+            orientation: obb.orientation.into(),
+        }
+    }
+}
+
+impl From<Obb> for sim::geometry::Obb {
+    #[inline]
+    fn from(obb: Obb) -> Self {
+        Self {
+            center: obb.center.into(),
+            half_width: obb.half_width.into(),
+            orientation: obb.orientation.into(),
+        }
+    }
+}
+
+#[pymethods]
+impl Obb {
+    #[new]
+    #[pyo3(signature = (center=Vec3::default(), half_width=Vec3::default(), orientation=Mat3::IDENTITY))]
+    fn __new__(center: Vec3, half_width: Vec3, orientation: Mat3) -> Self {
+        Self { center, half_width, orientation }
+    }
+
+    /// The 8 corners of the box in world coordinates
+    fn corners(&self) -> Vec<Vec3> {
+        let half_width: [f32; 3] = self.half_width.into();
+
+        (0..8)
+            .map(|i| {
+                let signs = [(i & 1) as f32 * 2. - 1., (i >> 1 & 1) as f32 * 2. - 1., (i >> 2 & 1) as f32 * 2. - 1.];
+                self.center + self.orientation.dot(Vec3::from(std::array::from_fn(|j| signs[j] * half_width[j])))
+            })
+            .collect()
+    }
+
+    /// The point in or on the box that is closest to `point`
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let local: [f32; 3] = self.orientation.transpose_dot(point - self.center).into();
+        let half_width: [f32; 3] = self.half_width.into();
+
+        self.center + self.orientation.dot(Vec3::from(std::array::from_fn(|i| local[i].clamp(-half_width[i], half_width[i]))))
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[inline]
+    fn __repr__(&self) -> String {
+        format!(
+            "obb(center={}, half_width={}, orientation={})",
+            self.center.__repr__(),
+            self.half_width.__repr__(),
+            self.orientation.__repr__()
+        )
+    }
+}
+
+#[pyclass(get_all, set_all)]
+#[derive(Clone, Copy, Debug, Default)]
+#[pyo3(name = "sphere")]
+pub struct Sphere {
+    center: Vec3,
+    radius: f32,
+}
+
+impl From<sim::geometry::Sphere> for Sphere {
+    #[inline]
+    fn from(sphere: sim::geometry::Sphere) -> Self {
+        Self {
+            center: sphere.center.into(),
+            radius: sphere.radius,
+        }
+    }
+}
+
+impl From<Sphere> for sim::geometry::Sphere {
+    #[inline]
+    fn from(sphere: Sphere) -> Self {
+        Self {
+            center: sphere.center.into(),
+            radius: sphere.radius,
+        }
+    }
+}
+
+impl Sphere {
+    #[inline]
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+}
+
+#[pymethods]
+impl Sphere {
+    #[new]
+    #[pyo3(signature = (center=Vec3::default(), radius=0.))]
+    fn __new__(center: Vec3, radius: f32) -> Self {
+        Self::new(center, radius)
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[inline]
+    fn __repr__(&self) -> String {
+        format!("sphere(center={}, radius={})", self.center.__repr__(), self.radius)
+    }
+}
+
+#[pyclass(get_all, set_all)]
+#[derive(Clone, Copy, Debug, Default)]
+#[pyo3(name = "ray")]
+pub struct Ray {
+    start: Vec3,
+    direction: Vec3,
+}
+
+impl From<sim::geometry::Ray> for Ray {
+    #[inline]
+    fn from(ray: sim::geometry::Ray) -> Self {
+        Self {
+            start: ray.start.into(),
+            direction: ray.direction.into(),
+        }
+    }
+}
+
+impl From<Ray> for sim::geometry::Ray {
+    #[inline]
+    fn from(ray: Ray) -> Self {
+        Self {
+            start: ray.start.into(),
+            direction: ray.direction.into(),
+        }
+    }
+}
+
+impl Ray {
+    #[inline]
+    pub fn new(start: Vec3, direction: Vec3) -> Self {
+        Self { start, direction }
+    }
+
+    #[inline]
+    pub fn get_start(&self) -> Vec3 {
+        self.start
+    }
+
+    #[inline]
+    pub fn get_direction(&self) -> Vec3 {
+        self.direction
+    }
+}
+
+#[pymethods]
+impl Ray {
+    #[new]
+    #[pyo3(signature = (start=Vec3::default(), direction=Vec3::default()))]
+    fn __new__(start: Vec3, direction: Vec3) -> Self {
+        Self::new(start, direction)
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[inline]
+    fn __repr__(&self) -> String {
+        format!("ray(start={}, direction={})", self.start.__repr__(), self.direction.__repr__())
+    }
+}
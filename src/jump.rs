@@ -0,0 +1,156 @@
+use crate::{
+    mech,
+    mechanic::{self, Mechanic},
+    sim, Car, Input,
+};
+use pyo3::prelude::*;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct JumpState {
+    inner: mech::jump::Jump,
+    double_jump: bool,
+    controls: sim::input::Input,
+    finished: bool,
+}
+
+impl Mechanic for JumpState {
+    fn step(&mut self, car: &sim::car::Car, dt: f32) {
+        if self.finished {
+            self.controls = sim::input::Input::default();
+            return;
+        }
+
+        if !self.inner.finished {
+            self.inner.step(car, dt);
+            self.controls = self.inner.controls;
+            // the frame where the first jump finishes releases the button,
+            // which is exactly what the game needs before it accepts the second jump
+            self.finished = self.inner.finished && !self.double_jump;
+            return;
+        }
+
+        // second jump - no stick input, otherwise the game turns it into a dodge
+        self.controls = sim::input::Input { jump: true, ..Default::default() };
+        self.finished = true;
+    }
+
+    #[inline]
+    fn controls(&self) -> sim::input::Input {
+        self.controls
+    }
+
+    #[inline]
+    fn finished(&self) -> bool {
+        self.finished
+    }
+}
+
+#[pyclass]
+pub struct Jump {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) state: JumpState,
+}
+
+impl fmt::Debug for Jump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jump")
+            .field("duration", &self.state.inner.duration)
+            .field("double_jump", &self.state.double_jump)
+            .field("timer", &self.state.inner.timer)
+            .field("controls", &self.state.controls)
+            .field("finished", &self.state.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl Jump {
+    #[new]
+    #[pyo3(signature = (car, duration, double_jump=false))]
+    fn __new__(car: Py<Car>, duration: f32, double_jump: bool) -> Self {
+        let mut inner = mech::jump::Jump::default();
+        inner.duration = duration;
+
+        Self {
+            car,
+            state: JumpState {
+                inner,
+                double_jump,
+                controls: sim::input::Input::default(),
+                finished: false,
+            },
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (state, car) = {
+            let this = slf.try_borrow()?;
+            (this.state.clone(), this.car.clone_ref(py))
+        };
+
+        let state = mechanic::step(py, state, &car, dt);
+        slf.try_borrow_mut()?.state = state;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (state, car) = (slf.state.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, state, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(duration)]
+    fn get_duration(&self) -> f32 {
+        self.state.inner.duration
+    }
+
+    #[inline]
+    #[setter(duration)]
+    fn set_duration(&mut self, duration: f32) {
+        self.state.inner.duration = duration;
+    }
+
+    #[inline]
+    #[getter(double_jump)]
+    fn get_double_jump(&self) -> bool {
+        self.state.double_jump
+    }
+
+    #[inline]
+    #[setter(double_jump)]
+    fn set_double_jump(&mut self, double_jump: bool) {
+        self.state.double_jump = double_jump;
+    }
+
+    #[inline]
+    #[getter(timer)]
+    fn get_timer(&self) -> f32 {
+        self.state.inner.timer
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.state.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.state.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -1,21 +1,52 @@
+mod aerial;
 mod ball;
+mod ball_prediction;
 mod base;
+mod boostdash;
 mod car;
+mod car_trajectory;
+mod curve;
+mod dodge;
 mod drive;
 mod field;
+mod follow_path;
 mod game;
+mod geometry;
 mod input;
+mod jump;
 mod math;
+mod mechanic;
+mod mode;
+mod navigator;
 mod pytypes;
+mod reorient;
+mod reorient_ml;
+mod sequence;
+mod wavedash;
 
+pub use aerial::Aerial;
 pub use ball::Ball;
-pub use car::Car;
+pub use ball_prediction::{predict_many, BallPrediction};
+pub use boostdash::Boostdash;
+pub use car::{Car, CarBody, CarState};
+pub use car_trajectory::CarTrajectory;
+pub use curve::{ControlPoint, Curve};
+pub use dodge::Dodge;
 pub use drive::Drive;
 pub use field::Field;
+pub use follow_path::FollowPath;
 pub use game::Game;
+pub use geometry::{Obb, Ray, Sphere};
 pub use input::Input;
-pub use math::{Mat3, Vec3};
-pub use rlutilities_rs::{cmat3, cvec3, linear_algebra as linalg, mechanics as mech, rlu, simulation as sim};
+pub use jump::Jump;
+pub use math::{Mat3, Vec2, Vec3};
+pub use mode::ArenaMode;
+pub use navigator::Navigator;
+pub use reorient::Reorient;
+pub use reorient_ml::ReorientML;
+pub use rlutilities_rs::{cmat3, cvec2, cvec3, linear_algebra as linalg, mechanics as mech, rlu, simulation as sim};
+pub use sequence::{MechanicParallel, MechanicSequence};
+pub use wavedash::Wavedash;
 
 use pyo3::{prelude::*, wrap_pyfunction, wrap_pymodule};
 use pytypes::{FieldInfoPacket, GameTickPacket};
@@ -38,7 +69,7 @@ pynamedmodule! {
     doc: "",
     name: mechanics,
     funcs: [],
-    classes: [Drive],
+    classes: [Drive, Aerial, Reorient, Dodge, Wavedash, Boostdash, FollowPath, Jump, ReorientML, MechanicSequence, MechanicParallel],
     submodules: []
 }
 
@@ -46,15 +77,15 @@ pynamedmodule! {
     doc: "",
     name: linear_algebra,
     funcs: [],
-    classes: [Vec3],
+    classes: [Vec2, Vec3, Mat3],
     submodules: []
 }
 
 pynamedmodule! {
     doc: "",
     name: simulation,
-    funcs: [],
-    classes: [Game, Ball, Field, Input, Car],
+    funcs: [predict_many],
+    classes: [Game, Ball, BallPrediction, Field, Input, Car, CarBody, CarState, CarTrajectory, Obb, Sphere, Ray, ControlPoint, Curve, Navigator],
     submodules: []
 }
 
@@ -1,5 +1,6 @@
-use crate::{cmat3, cvec3};
+use crate::{cmat3, cvec2, cvec3};
 use pyo3::{exceptions::PyIndexError, prelude::*, pyclass::CompareOp, types::PyTuple};
+use std::ops::{Add, Mul, Sub};
 
 #[pyclass]
 #[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
@@ -20,6 +21,97 @@ impl From<Mat3> for cmat3 {
     }
 }
 
+impl Mat3 {
+    pub const IDENTITY: Self = Self([1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+
+    #[inline]
+    pub fn column(&self, j: usize) -> Vec3 {
+        Vec3([self.0[j], self.0[Self::SIZE + j], self.0[2 * Self::SIZE + j]])
+    }
+
+    /// `self * v`
+    #[inline]
+    pub fn dot(&self, v: Vec3) -> Vec3 {
+        Vec3(std::array::from_fn(|i| (0..Self::SIZE).map(|j| self.0[i * Self::SIZE + j] * v.0[j]).sum()))
+    }
+
+    /// `transpose(self) * v`
+    #[inline]
+    pub fn transpose_dot(&self, v: Vec3) -> Vec3 {
+        Vec3(std::array::from_fn(|j| (0..Self::SIZE).map(|i| self.0[i * Self::SIZE + j] * v.0[i]).sum()))
+    }
+}
+
+#[pymethods]
+impl Mat3 {
+    const SIZE: usize = 3;
+
+    #[new]
+    #[pyo3(signature = (*args))]
+    fn new(args: &PyTuple) -> Self {
+        if let Ok(args) = args.get_item(0).and_then(PyAny::extract) {
+            return args;
+        }
+
+        let mut mat = [0.; 9];
+
+        if let Ok(rows) = args.get_item(0).and_then(PyAny::extract::<Vec<Vec<f32>>>) {
+            for (i, row) in rows.into_iter().take(Self::SIZE).enumerate() {
+                mat[i * Self::SIZE..].iter_mut().zip(row.into_iter().take(Self::SIZE)).for_each(|(a, b)| *a = b);
+            }
+        } else if let Ok(args) = args.extract::<Vec<f32>>() {
+            mat.iter_mut().zip(args.into_iter()).for_each(|(a, b)| *a = b);
+        }
+
+        Self(mat)
+    }
+
+    #[inline]
+    fn __getitem__(&self, index: (usize, usize)) -> PyResult<f32> {
+        if index.0 >= Self::SIZE || index.1 >= Self::SIZE {
+            Err(PyIndexError::new_err("index out of range"))
+        } else {
+            Ok(self.0[index.0 * Self::SIZE + index.1])
+        }
+    }
+
+    #[inline]
+    fn __setitem__(&mut self, index: (usize, usize), value: f32) -> PyResult<()> {
+        if index.0 >= Self::SIZE || index.1 >= Self::SIZE {
+            Err(PyIndexError::new_err("index out of range"))
+        } else {
+            self.0[index.0 * Self::SIZE + index.1] = value;
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[inline]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "mat3({}, {}, {}, {}, {}, {}, {}, {}, {})",
+            self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5], self.0[6], self.0[7], self.0[8]
+        )
+    }
+
+    /// Only == and != are actually supported right now
+    fn __richcmp__(&self, other: Self, op: CompareOp) -> bool {
+        if !matches!(op, CompareOp::Eq | CompareOp::Ne) {
+            return false;
+        };
+
+        let Some(cmp) = self.partial_cmp(&other) else {
+            return false;
+        };
+
+        op.matches(cmp)
+    }
+}
+
 #[pyclass]
 #[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
 #[pyo3(name = "vec3")]
@@ -54,6 +146,50 @@ impl From<Vec3> for cvec3 {
     }
 }
 
+impl Vec3 {
+    #[inline]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + (other.0[i] - self.0[i]) * t))
+    }
+
+    #[inline]
+    pub fn dot(self, other: Self) -> f32 {
+        self.0.iter().zip(other.0).map(|(a, b)| a * b).sum()
+    }
+
+    #[inline]
+    pub fn norm(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0.map(|x| x * rhs))
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
 #[pymethods]
 impl Vec3 {
     const NAMES: [&str; 3] = ["x", "y", "z"];
@@ -168,3 +304,131 @@ impl Vec3 {
         op.matches(cmp)
     }
 }
+
+#[pyclass]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
+#[pyo3(name = "vec2")]
+#[repr(transparent)]
+pub struct Vec2([f32; 2]);
+
+impl From<Vec3> for Vec2 {
+    #[inline]
+    fn from(value: Vec3) -> Self {
+        Self([value.0[0], value.0[1]])
+    }
+}
+
+impl From<cvec2> for Vec2 {
+    #[inline]
+    fn from(value: cvec2) -> Self {
+        Self(value.data)
+    }
+}
+
+impl From<Vec2> for cvec2 {
+    #[inline]
+    fn from(value: Vec2) -> Self {
+        Self { data: value.0 }
+    }
+}
+
+#[pymethods]
+impl Vec2 {
+    const NAMES: [&str; 2] = ["x", "y"];
+
+    #[new]
+    #[pyo3(signature = (*args, **kwargs))]
+    fn new(args: &PyTuple, kwargs: Option<&PyAny>) -> Self {
+        if let Ok(args) = args.get_item(0).and_then(PyAny::extract) {
+            return args;
+        }
+
+        if let Ok(args) = args.get_item(0).and_then(PyAny::extract::<Vec3>) {
+            return args.into();
+        }
+
+        let mut vec = [None; Self::NAMES.len()];
+
+        if let Ok(args) = args.get_item(0).and_then(PyAny::extract::<Vec<f32>>) {
+            vec.iter_mut().zip(args.into_iter()).for_each(|(a, b)| *a = Some(b));
+        } else if let Ok(args) = args.extract::<Vec<f32>>() {
+            vec.iter_mut().zip(args.into_iter()).for_each(|(a, b)| *a = Some(b));
+        }
+
+        if let Some(kwargs) = kwargs {
+            for (a, b) in vec.iter_mut().zip(Self::NAMES.into_iter()) {
+                if let Ok(x) = kwargs.get_item(b).and_then(PyAny::extract) {
+                    *a = Some(x);
+                }
+            }
+        }
+
+        Self([vec[0].unwrap_or_default(), vec[1].unwrap_or_default()])
+    }
+
+    #[inline]
+    fn __getitem__(&self, index: usize) -> PyResult<f32> {
+        if index >= Self::NAMES.len() {
+            Err(PyIndexError::new_err("index out of range"))
+        } else {
+            Ok(self.0[index])
+        }
+    }
+
+    #[inline]
+    fn __setitem__(&mut self, index: usize, value: f32) -> PyResult<()> {
+        if index >= Self::NAMES.len() {
+            Err(PyIndexError::new_err("index out of range"))
+        } else {
+            self.0[index] = value;
+            Ok(())
+        }
+    }
+
+    #[inline]
+    #[getter(x)]
+    fn get_x(&self) -> f32 {
+        self.0[0]
+    }
+
+    #[inline]
+    #[setter(x)]
+    fn set_x(&mut self, x: f32) {
+        self.0[0] = x;
+    }
+
+    #[inline]
+    #[getter(y)]
+    fn get_y(&self) -> f32 {
+        self.0[1]
+    }
+
+    #[inline]
+    #[setter(y)]
+    fn set_y(&mut self, y: f32) {
+        self.0[1] = y;
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[inline]
+    pub fn __repr__(&self) -> String {
+        format!("vec2(x={}, y={})", self.0[0], self.0[1])
+    }
+
+    /// Only == and != are actually supported right now
+    fn __richcmp__(&self, other: Self, op: CompareOp) -> bool {
+        if !matches!(op, CompareOp::Eq | CompareOp::Ne) {
+            return false;
+        };
+
+        let Some(cmp) = self.partial_cmp(&other) else {
+            return false;
+        };
+
+        op.matches(cmp)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    base::{step_count, FromGil, RemoveGil},
+    jump::JumpState,
+    mech,
+    sequence::{ParallelState, SequenceState},
+    sim, Aerial, Boostdash, Car, Dodge, Drive, FollowPath, Jump, MechanicParallel, MechanicSequence, Reorient, ReorientML, Wavedash,
+};
+use pyo3::{exceptions::PyTypeError, prelude::*};
+
+pub trait Mechanic {
+    fn step(&mut self, car: &sim::car::Car, dt: f32);
+    fn controls(&self) -> sim::input::Input;
+    fn finished(&self) -> bool;
+}
+
+macro_rules! impl_mechanic {
+    ($($t:ty),*) => {
+        $(
+            impl Mechanic for $t {
+                #[inline]
+                fn step(&mut self, car: &sim::car::Car, dt: f32) {
+                    <$t>::step(self, car, dt);
+                }
+
+                #[inline]
+                fn controls(&self) -> sim::input::Input {
+                    self.controls
+                }
+
+                #[inline]
+                fn finished(&self) -> bool {
+                    self.finished
+                }
+            }
+        )*
+    };
+}
+
+impl_mechanic!(
+    mech::aerial::Aerial,
+    mech::drive::Drive,
+    mech::reorient::Reorient,
+    mech::reorient_ml::ReorientML,
+    mech::dodge::Dodge,
+    mech::wavedash::Wavedash,
+    mech::boostdash::Boostdash,
+    mech::follow_path::FollowPath
+);
+
+/// A copy of any of the bound mechanics, so they can be chained together on the Rust side
+///
+/// This is a snapshot taken when it's extracted - changing the Python object afterwards
+/// has no effect on the copy, and stepping the copy doesn't update the Python object
+#[derive(Clone, Debug)]
+pub enum AnyMechanic {
+    Aerial(mech::aerial::Aerial),
+    Drive(mech::drive::Drive),
+    Reorient(mech::reorient::Reorient),
+    ReorientML(mech::reorient_ml::ReorientML),
+    Dodge(mech::dodge::Dodge),
+    Wavedash(mech::wavedash::Wavedash),
+    Boostdash(mech::boostdash::Boostdash),
+    FollowPath(mech::follow_path::FollowPath),
+    Jump(JumpState),
+    Sequence(SequenceState),
+    Parallel(ParallelState),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $m:ident => $e:expr) => {
+        match $self {
+            AnyMechanic::Aerial($m) => $e,
+            AnyMechanic::Drive($m) => $e,
+            AnyMechanic::Reorient($m) => $e,
+            AnyMechanic::ReorientML($m) => $e,
+            AnyMechanic::Dodge($m) => $e,
+            AnyMechanic::Wavedash($m) => $e,
+            AnyMechanic::Boostdash($m) => $e,
+            AnyMechanic::FollowPath($m) => $e,
+            AnyMechanic::Jump($m) => $e,
+            AnyMechanic::Sequence($m) => $e,
+            AnyMechanic::Parallel($m) => $e,
+        }
+    };
+}
+
+impl Mechanic for AnyMechanic {
+    #[inline]
+    fn step(&mut self, car: &sim::car::Car, dt: f32) {
+        dispatch!(self, m => Mechanic::step(m, car, dt));
+    }
+
+    #[inline]
+    fn controls(&self) -> sim::input::Input {
+        dispatch!(self, m => Mechanic::controls(m))
+    }
+
+    #[inline]
+    fn finished(&self) -> bool {
+        dispatch!(self, m => Mechanic::finished(m))
+    }
+}
+
+impl<'a> FromPyObject<'a> for AnyMechanic {
+    fn extract(ob: &'a PyAny) -> PyResult<Self> {
+        if let Ok(m) = ob.extract::<PyRef<Aerial>>() {
+            Ok(Self::Aerial(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<Drive>>() {
+            Ok(Self::Drive(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<Reorient>>() {
+            Ok(Self::Reorient(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<ReorientML>>() {
+            Ok(Self::ReorientML(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<Dodge>>() {
+            Ok(Self::Dodge(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<Wavedash>>() {
+            Ok(Self::Wavedash(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<Boostdash>>() {
+            Ok(Self::Boostdash(m.inner.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<FollowPath>>() {
+            Ok(Self::FollowPath(m.planned()?.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<Jump>>() {
+            Ok(Self::Jump(m.state.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<MechanicSequence>>() {
+            Ok(Self::Sequence(m.state.clone()))
+        } else if let Ok(m) = ob.extract::<PyRef<MechanicParallel>>() {
+            Ok(Self::Parallel(m.state.clone()))
+        } else {
+            Err(PyTypeError::new_err(format!("{} is not a mechanic", ob.get_type().name()?)))
+        }
+    }
+}
+
+/// Steps `mechanic` for one tick in the car's arena without holding the GIL, returning the stepped mechanic
+///
+/// Pass in a copy so the Python object isn't borrowed while this runs, then write the result back
+pub fn step<M: Mechanic + Send>(py: Python, mut mechanic: M, car: &Py<Car>, dt: f32) -> M {
+    let car = car.borrow(py).clone();
+    let mode = car.get_mode();
+    let car = car.remove_gil(py);
+    py.allow_threads(|| mode.activate(|| mechanic.step(&car, dt)));
+
+    mechanic
+}
+
+/// Runs `simulate` in the car's arena without holding the GIL
+///
+/// Raises a ValueError if `dt` isn't positive, either argument isn't finite or there would be too many steps
+///
+/// Callers should copy what they need out of their own object and release its borrow first,
+/// otherwise Python code on other threads can't touch it until this returns
+pub fn simulate_car<M: Mechanic + Send>(py: Python, mechanic: M, car: Car, dt: f32, max_time: f32) -> PyResult<(Car, f32)> {
+    let steps = step_count(max_time, dt)?;
+    let mode = car.get_mode();
+    let car = car.remove_gil(py);
+    let (car, time) = py.allow_threads(|| mode.activate(|| simulate(mechanic, car, dt, steps)));
+    Ok((Car::from_gil(py, car)?.with_mode(mode), time))
+}
+
+/// Runs `mechanic` against `car` until it finishes or `steps` steps of `dt` have passed,
+/// returning the car's final state and the time that elapsed
+///
+/// The car's arena must already be active
+pub fn simulate<M: Mechanic>(mut mechanic: M, mut car: sim::car::Car, dt: f32, steps: usize) -> (sim::car::Car, f32) {
+    let mut taken = 0;
+
+    while taken < steps {
+        mechanic.step(&car, dt);
+        car.step(mechanic.controls(), dt);
+        taken += 1;
+
+        if mechanic.finished() {
+            break;
+        }
+    }
+
+    (car, taken as f32 * dt)
+}
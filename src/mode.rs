@@ -0,0 +1,99 @@
+use crate::sim;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::sync::{Mutex, PoisonError, RwLock};
+
+/// The mode used by objects that weren't given one, changed with `Game.set_mode`
+static DEFAULT_MODE: Mutex<ArenaMode> = Mutex::new(ArenaMode::Soccar);
+
+/// The mode that's currently loaded into rlutilities_rs, which only supports one at a time
+///
+/// Anything that touches the simulation holds a read lock for as long as it runs,
+/// switching to another mode takes the write lock and rebuilds the arena
+static ACTIVE_MODE: RwLock<Option<ArenaMode>> = RwLock::new(None);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArenaMode {
+    Soccar,
+    Hoops,
+    Dropshot,
+    Throwback,
+}
+
+impl ArenaMode {
+    const ALL: [Self; 4] = [Self::Soccar, Self::Hoops, Self::Dropshot, Self::Throwback];
+
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Soccar => "soccar",
+            Self::Hoops => "hoops",
+            Self::Dropshot => "dropshot",
+            Self::Throwback => "throwback",
+        }
+    }
+
+    #[inline]
+    pub fn default_mode() -> Self {
+        *DEFAULT_MODE.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[inline]
+    pub fn set_default_mode(self) {
+        *DEFAULT_MODE.lock().unwrap_or_else(PoisonError::into_inner) = self;
+    }
+
+    /// Loads this mode's arena if it isn't already
+    #[inline]
+    pub fn load(self) {
+        self.activate(|| ());
+    }
+
+    /// Runs `f` with this mode's arena loaded
+    ///
+    /// Calls in the same mode run concurrently, but calls in different modes take turns
+    /// and every switch rebuilds the arena, so alternating modes is slow.
+    /// Call this with the GIL released, and `f` must not wait on the GIL
+    pub fn activate<T>(self, f: impl FnOnce() -> T) -> T {
+        // another thread may switch modes between us loading the arena and getting the read lock
+        let _active = loop {
+            let active = ACTIVE_MODE.read().unwrap_or_else(PoisonError::into_inner);
+            if *active == Some(self) {
+                break active;
+            }
+            drop(active);
+
+            let mut active = ACTIVE_MODE.write().unwrap_or_else(PoisonError::into_inner);
+            if *active != Some(self) {
+                sim::game::Game::set_mode(self.as_str().to_string());
+                *active = Some(self);
+            }
+        };
+
+        f()
+    }
+}
+
+impl TryFrom<&str> for ArenaMode {
+    type Error = PyErr;
+
+    fn try_from(mode: &str) -> PyResult<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == mode).ok_or_else(|| {
+            let modes = Self::ALL.map(Self::as_str).join(", ");
+            PyValueError::new_err(format!("unknown mode '{mode}', expected one of: {modes}"))
+        })
+    }
+}
+
+impl<'a> FromPyObject<'a> for ArenaMode {
+    #[inline]
+    fn extract(ob: &'a PyAny) -> PyResult<Self> {
+        Self::try_from(ob.extract::<&str>()?)
+    }
+}
+
+impl IntoPy<PyObject> for ArenaMode {
+    #[inline]
+    fn into_py(self, py: Python) -> PyObject {
+        self.as_str().into_py(py)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{base::RemoveGil, sim, ArenaMode, Car, Curve, Vec3};
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+};
+use std::sync::{Arc, Mutex, PoisonError};
+
+#[pyclass]
+pub struct Navigator {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    // shared so the navigation graph can be searched without holding a borrow of this object
+    inner: Arc<Mutex<sim::navigator::Navigator>>,
+    analyzed: bool,
+}
+
+/// The navigation graph only covers the soccar arena
+fn check_mode(car: &Car) -> PyResult<()> {
+    match car.get_mode() {
+        ArenaMode::Soccar => Ok(()),
+        mode => Err(PyValueError::new_err(format!("the navigator only supports soccar, but the car is in {}", mode.as_str()))),
+    }
+}
+
+#[pymethods]
+impl Navigator {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: Arc::default(),
+            analyzed: false,
+        }
+    }
+
+    /// Builds the shortest-path tree over the soccar navigation graph, starting from the car's current state
+    ///
+    /// Raises a ValueError if the car isn't in soccar
+    fn analyze_surroundings(slf: &PyCell<Self>, time_budget: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (car, inner) = {
+            let this = slf.try_borrow()?;
+            let car = this.car.borrow(py).clone();
+            check_mode(&car)?;
+            (car.remove_gil(py), this.inner.clone())
+        };
+
+        py.allow_threads(|| ArenaMode::Soccar.activate(|| inner.lock().unwrap_or_else(PoisonError::into_inner).analyze_surroundings(&car, time_budget)));
+        slf.try_borrow_mut()?.analyzed = true;
+
+        Ok(())
+    }
+
+    /// Returns a path from the car to `target` that arrives travelling along `direction` at `speed`
+    ///
+    /// Raises a RuntimeError if `analyze_surroundings` hasn't been called yet
+    fn path_to(slf: PyRef<Self>, target: Vec3, direction: Vec3, speed: f32) -> PyResult<Curve> {
+        if !slf.analyzed {
+            return Err(PyRuntimeError::new_err("analyze_surroundings must be called before path_to"));
+        }
+
+        let py = slf.py();
+        check_mode(&slf.car.borrow(py))?;
+        let inner = slf.inner.clone();
+        drop(slf);
+
+        Ok(py
+            .allow_threads(|| ArenaMode::Soccar.activate(|| inner.lock().unwrap_or_else(PoisonError::into_inner).path_to(target.into(), direction.into(), speed)))
+            .into())
+    }
+}
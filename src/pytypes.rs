@@ -99,10 +99,11 @@ pub struct Hitbox {
 }
 
 impl From<Hitbox> for cvec3 {
+    /// RLUtilities stores the half widths of the hitbox, not the full dimensions
     #[inline]
     fn from(value: Hitbox) -> Self {
         Self {
-            data: [value.length, value.width, value.height],
+            data: [value.length / 2., value.width / 2., value.height / 2.],
         }
     }
 }
@@ -0,0 +1,123 @@
+use crate::{mech, mechanic, Car, Input, Mat3};
+use pyo3::prelude::*;
+use std::fmt;
+
+#[pyclass]
+pub struct Reorient {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) inner: mech::reorient::Reorient,
+}
+
+impl fmt::Debug for Reorient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reorient")
+            .field("target_orientation", &self.inner.target_orientation)
+            .field("eps_phi", &self.inner.eps_phi)
+            .field("eps_omega", &self.inner.eps_omega)
+            .field("horizon_time", &self.inner.horizon_time)
+            .field("controls", &self.inner.controls)
+            .field("finished", &self.inner.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl Reorient {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: mech::reorient::Reorient::default(),
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (inner, car) = (slf.inner.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, inner, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(target_orientation)]
+    fn get_target_orientation(&self) -> Mat3 {
+        self.inner.target_orientation.into()
+    }
+
+    #[inline]
+    #[setter(target_orientation)]
+    fn set_target_orientation(&mut self, target_orientation: Mat3) {
+        self.inner.target_orientation = target_orientation.into();
+    }
+
+    #[inline]
+    #[getter(eps_phi)]
+    fn get_eps_phi(&self) -> f32 {
+        self.inner.eps_phi
+    }
+
+    #[inline]
+    #[setter(eps_phi)]
+    fn set_eps_phi(&mut self, eps_phi: f32) {
+        self.inner.eps_phi = eps_phi;
+    }
+
+    #[inline]
+    #[getter(eps_omega)]
+    fn get_eps_omega(&self) -> f32 {
+        self.inner.eps_omega
+    }
+
+    #[inline]
+    #[setter(eps_omega)]
+    fn set_eps_omega(&mut self, eps_omega: f32) {
+        self.inner.eps_omega = eps_omega;
+    }
+
+    #[inline]
+    #[getter(horizon_time)]
+    fn get_horizon_time(&self) -> f32 {
+        self.inner.horizon_time
+    }
+
+    #[inline]
+    #[setter(horizon_time)]
+    fn set_horizon_time(&mut self, horizon_time: f32) {
+        self.inner.horizon_time = horizon_time;
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.inner.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.inner.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
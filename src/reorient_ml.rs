@@ -0,0 +1,86 @@
+use crate::{mech, mechanic, Car, Input, Mat3};
+use pyo3::prelude::*;
+use std::fmt;
+
+/// Air control using the learned policy from `assets/ML/reorient_ML_model.bin`,
+/// which is loaded by `initialize`
+#[pyclass]
+pub struct ReorientML {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) inner: mech::reorient_ml::ReorientML,
+}
+
+impl fmt::Debug for ReorientML {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReorientML")
+            .field("target_orientation", &self.inner.target_orientation)
+            .field("controls", &self.inner.controls)
+            .field("finished", &self.inner.finished)
+            .finish()
+    }
+}
+
+#[pymethods]
+impl ReorientML {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>) -> Self {
+        Self {
+            car,
+            inner: mech::reorient_ml::ReorientML::default(),
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (inner, car) = {
+            let this = slf.try_borrow()?;
+            (this.inner.clone(), this.car.clone_ref(py))
+        };
+
+        let inner = mechanic::step(py, inner, &car, dt);
+        slf.try_borrow_mut()?.inner = inner;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (inner, car) = (slf.inner.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, inner, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(target_orientation)]
+    fn get_target_orientation(&self) -> Mat3 {
+        self.inner.target_orientation.into()
+    }
+
+    #[inline]
+    #[setter(target_orientation)]
+    fn set_target_orientation(&mut self, target_orientation: Mat3) {
+        self.inner.target_orientation = target_orientation.into();
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.inner.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.inner.finished
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    mechanic::{self, AnyMechanic, Mechanic},
+    sim, Car, Input,
+};
+use pyo3::prelude::*;
+use std::fmt;
+
+/// Merges the controls of mechanics running side by side, earlier ones first
+///
+/// Steering and throttle come from the first mechanic that uses either of them, and pitch, yaw and roll
+/// from the first that uses any of those, so a ground mechanic and an air mechanic can share the car.
+/// Buttons are pressed if any mechanic presses them
+fn merge_controls(controls: impl IntoIterator<Item = sim::input::Input>) -> sim::input::Input {
+    let mut merged = sim::input::Input::default();
+    let (mut ground, mut air) = (false, false);
+
+    for other in controls {
+        if !ground && (other.steer != 0. || other.throttle != 0.) {
+            merged.steer = other.steer;
+            merged.throttle = other.throttle;
+            ground = true;
+        }
+
+        if !air && (other.pitch != 0. || other.yaw != 0. || other.roll != 0.) {
+            merged.pitch = other.pitch;
+            merged.yaw = other.yaw;
+            merged.roll = other.roll;
+            air = true;
+        }
+
+        merged.jump |= other.jump;
+        merged.boost |= other.boost;
+        merged.handbrake |= other.handbrake;
+        merged.use_item |= other.use_item;
+    }
+
+    merged
+}
+
+#[derive(Clone, Debug)]
+pub struct SequenceState {
+    mechanics: Vec<AnyMechanic>,
+    index: usize,
+    controls: sim::input::Input,
+}
+
+impl Mechanic for SequenceState {
+    fn step(&mut self, car: &sim::car::Car, dt: f32) {
+        let Some(mechanic) = self.mechanics.get_mut(self.index) else {
+            self.controls = sim::input::Input::default();
+            return;
+        };
+
+        mechanic.step(car, dt);
+        self.controls = mechanic.controls();
+
+        if mechanic.finished() {
+            self.index += 1;
+        }
+    }
+
+    #[inline]
+    fn controls(&self) -> sim::input::Input {
+        self.controls
+    }
+
+    #[inline]
+    fn finished(&self) -> bool {
+        self.index >= self.mechanics.len()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ParallelState {
+    mechanics: Vec<AnyMechanic>,
+    controls: sim::input::Input,
+}
+
+impl Mechanic for ParallelState {
+    fn step(&mut self, car: &sim::car::Car, dt: f32) {
+        let controls: Vec<_> = self
+            .mechanics
+            .iter_mut()
+            .filter(|m| !m.finished())
+            .map(|m| {
+                m.step(car, dt);
+                m.controls()
+            })
+            .collect();
+
+        self.controls = merge_controls(controls);
+    }
+
+    #[inline]
+    fn controls(&self) -> sim::input::Input {
+        self.controls
+    }
+
+    #[inline]
+    fn finished(&self) -> bool {
+        self.mechanics.iter().all(Mechanic::finished)
+    }
+}
+
+/// Runs each mechanic until it finishes, then hands the car over to the next one
+///
+/// The mechanics are copied in when it's created, see `AnyMechanic`
+#[pyclass]
+pub struct MechanicSequence {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) state: SequenceState,
+}
+
+impl fmt::Debug for MechanicSequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MechanicSequence")
+            .field("mechanics", &self.state.mechanics.len())
+            .field("index", &self.state.index)
+            .field("controls", &self.state.controls)
+            .field("finished", &self.state.finished())
+            .finish()
+    }
+}
+
+#[pymethods]
+impl MechanicSequence {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>, mechanics: Vec<AnyMechanic>) -> Self {
+        Self {
+            car,
+            state: SequenceState {
+                mechanics,
+                index: 0,
+                controls: sim::input::Input::default(),
+            },
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (state, car) = {
+            let this = slf.try_borrow()?;
+            (this.state.clone(), this.car.clone_ref(py))
+        };
+
+        let state = mechanic::step(py, state, &car, dt);
+        slf.try_borrow_mut()?.state = state;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (state, car) = (slf.state.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, state, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(index)]
+    fn get_index(&self) -> usize {
+        self.state.index
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.state.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.state.finished()
+    }
+
+    #[inline]
+    fn __len__(&self) -> usize {
+        self.state.mechanics.len()
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+/// Steps every unfinished mechanic each tick and merges their controls, with earlier mechanics
+/// in the list taking priority on steering and throttle, and separately on pitch, yaw and roll
+///
+/// The mechanics are copied in when it's created, see `AnyMechanic`
+#[pyclass]
+pub struct MechanicParallel {
+    #[pyo3(get, set)]
+    car: Py<Car>,
+    pub(crate) state: ParallelState,
+}
+
+impl fmt::Debug for MechanicParallel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MechanicParallel")
+            .field("mechanics", &self.state.mechanics.len())
+            .field("controls", &self.state.controls)
+            .field("finished", &self.state.finished())
+            .finish()
+    }
+}
+
+#[pymethods]
+impl MechanicParallel {
+    #[new]
+    #[inline]
+    fn __new__(car: Py<Car>, mechanics: Vec<AnyMechanic>) -> Self {
+        Self {
+            car,
+            state: ParallelState {
+                mechanics,
+                controls: sim::input::Input::default(),
+            },
+        }
+    }
+
+    #[inline]
+    fn step(slf: &PyCell<Self>, dt: f32) -> PyResult<()> {
+        let py = slf.py();
+        let (state, car) = {
+            let this = slf.try_borrow()?;
+            (this.state.clone(), this.car.clone_ref(py))
+        };
+
+        let state = mechanic::step(py, state, &car, dt);
+        slf.try_borrow_mut()?.state = state;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (max_time, dt=1. / 120.))]
+    fn simulate(slf: PyRef<Self>, max_time: f32, dt: f32) -> PyResult<(Car, f32)> {
+        let py = slf.py();
+        let (state, car) = (slf.state.clone(), slf.car.borrow(py).clone());
+        drop(slf);
+
+        mechanic::simulate_car(py, state, car, dt, max_time)
+    }
+
+    #[inline]
+    #[getter(controls)]
+    fn get_controls(&self) -> Input {
+        self.state.controls.into()
+    }
+
+    #[inline]
+    #[getter(finished)]
+    fn get_finished(&self) -> bool {
+        self.state.finished()
+    }
+
+    #[inline]
+    fn __len__(&self) -> usize {
+        self.state.mechanics.len()
+    }
+
+    #[inline]
+    fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}